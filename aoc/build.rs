@@ -0,0 +1,61 @@
+//! Generates the registry of solutions from the `day-XX` dependencies in `Cargo.toml`.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    // a new `day-XX` crate joins the workspace through the `day-*` glob, which adds it to the
+    // lock file; watching the workspace directory instead would rerun on every build, as it
+    // holds `target`
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+    let manifest: toml::Table = fs::read_to_string("Cargo.toml")
+        .expect("Could not read aoc/Cargo.toml")
+        .parse()
+        .expect("Could not parse aoc/Cargo.toml");
+    let mut days: Vec<usize> = manifest
+        .get("dependencies")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|dependencies| dependencies.keys())
+        .filter_map(|name| day_of(name))
+        .collect();
+    days.sort();
+
+    let solvers: String = days
+        .iter()
+        .map(|day| {
+            format!(
+                "    Solver::new::<day_{:02}::Day{:02}>({}),\n",
+                day, day, day
+            )
+        })
+        .collect();
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("solvers.rs"),
+        format!("[\n{}]\n", solvers),
+    )
+    .expect("Could not write the registry");
+
+    // days generated from the template that aoc does not depend on yet
+    let Ok(entries) = fs::read_dir("..") else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(day) = day_of(&name)
+            && !days.contains(&day)
+            && entry.path().join("Cargo.toml").is_file()
+        {
+            println!(
+                "cargo:warning={} has no solution registered, add `{} = {{ path = \"../{}\" }}` to aoc/Cargo.toml",
+                name, name, name
+            );
+        }
+    }
+}
+
+/// The day a crate solves, from its package name, e.g. `day-05`.
+fn day_of(name: &str) -> Option<usize> {
+    name.strip_prefix("day-")?.parse().ok()
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Local};
+use clap::Subcommand;
+use color_eyre::Result;
+use util::AocClient;
+
+use crate::{days::Days, resolve_year};
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// List the cached inputs with their size and fetch time
+    List,
+    /// Print a cached input
+    Show {
+        day: usize,
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+    },
+    /// Remove a day's input, or every input of the profile, from the cache
+    Evict {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<usize>,
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+        /// Remove every cached input of the profile, e.g. after its session cookie changed
+        #[arg(long)]
+        all: bool,
+    },
+    /// Fetch inputs into the cache, e.g. `aoc cache prefetch 1..=12`
+    Prefetch {
+        days: Days,
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+    },
+}
+
+pub async fn run(command: CacheCommand) -> Result<()> {
+    let client = AocClient::from_env();
+    match command {
+        CacheCommand::List => {
+            let entries = client.cached_inputs()?;
+            if entries.is_empty() {
+                println!("No inputs cached in {}", client.cache_dir().display());
+            }
+            for entry in entries {
+                println!(
+                    "{:<10} {} day {:>2}  {:>6} bytes  fetched {}",
+                    entry.profile,
+                    entry.year,
+                    entry.day,
+                    entry.size,
+                    DateTime::<Local>::from(entry.fetched_at).format("%Y-%m-%d %H:%M:%S")
+                );
+            }
+        }
+        CacheCommand::Show { day, year } => {
+            let year = resolve_year(year)?;
+            match client.cached_input(year, day)? {
+                Some(input) => print!("{}", input),
+                None => println!("Input for year {}, day {} is not cached", year, day),
+            }
+        }
+        CacheCommand::Evict {
+            day: Some(day),
+            year,
+            all: false,
+        } => {
+            let year = resolve_year(year)?;
+            if client.evict(year, day)? {
+                println!("Evicted input for year {}, day {}", year, day);
+            } else {
+                println!("Input for year {}, day {} is not cached", year, day);
+            }
+        }
+        CacheCommand::Evict { .. } => {
+            let evicted = client.evict_all()?;
+            println!("Evicted {} inputs", evicted);
+        }
+        CacheCommand::Prefetch { days, year } => {
+            let year = resolve_year(year)?;
+            for day in days.0 {
+                match client.get_input(year, day).await {
+                    Ok(input) => println!("Year {}, day {}: {} bytes", year, day, input.len()),
+                    Err(e) => println!("Year {}, day {}: {}", year, day, e),
+                }
+            }
+        }
+    }
+    Ok(())
+}
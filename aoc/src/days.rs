@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use color_eyre::eyre::{Report, eyre};
+
+/// A set of days given on the command line as `5`, `1..=12`, `1..13` or `1-12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(pub Vec<usize>);
+
+impl FromStr for Days {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |day: &str| {
+            day.trim()
+                .parse::<usize>()
+                .map_err(|_| eyre!("invalid day {:?}", day))
+        };
+        let days: Vec<usize> = if let Some((start, end)) = s.split_once("..=") {
+            (parse(start)?..=parse(end)?).collect()
+        } else if let Some((start, end)) = s.split_once("..") {
+            (parse(start)?..parse(end)?).collect()
+        } else if let Some((start, end)) = s.split_once('-') {
+            (parse(start)?..=parse(end)?).collect()
+        } else {
+            vec![parse(s)?]
+        };
+        if days.is_empty() {
+            return Err(eyre!("{:?} does not contain any days", s));
+        }
+        Ok(Days(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("5", vec![5])]
+    #[case("1..=3", vec![1, 2, 3])]
+    #[case("1..3", vec![1, 2])]
+    #[case("10-12", vec![10, 11, 12])]
+    fn test_parse_days(#[case] input: &str, #[case] expected: Vec<usize>) {
+        assert_eq!(input.parse::<Days>().unwrap(), Days(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("x")]
+    #[case("3..3")]
+    #[case("1..=")]
+    fn test_parse_days_invalid(#[case] input: &str) {
+        assert!(input.parse::<Days>().is_err());
+    }
+}
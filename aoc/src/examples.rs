@@ -0,0 +1,32 @@
+use clap::Args;
+use color_eyre::Result;
+use util::{AocClient, examples_dir, extract_examples, write_examples};
+
+use crate::{days::Days, resolve_year};
+
+#[derive(Debug, Args)]
+pub struct ExamplesArgs {
+    days: Days,
+    /// Defaults to the year of the workspace
+    #[arg(long)]
+    year: Option<usize>,
+}
+
+pub async fn run(args: ExamplesArgs) -> Result<()> {
+    let client = AocClient::from_env();
+    let year = resolve_year(args.year)?;
+    for day in args.days.0 {
+        let page = client.get_puzzle_page(year, day).await?;
+        let examples = extract_examples(&page);
+        let crate_dir = client.day_dir(day);
+        write_examples(&crate_dir, &examples)?;
+        println!(
+            "Day {}: wrote {} examples and {} answers to {}",
+            day,
+            examples.blocks.len(),
+            examples.answers.iter().flatten().count(),
+            examples_dir(&crate_dir).display()
+        );
+    }
+    Ok(())
+}
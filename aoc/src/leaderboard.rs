@@ -0,0 +1,43 @@
+use clap::Args;
+use color_eyre::{Result, eyre::eyre};
+use util::{AocClient, FetchError, days_in};
+
+use crate::resolve_year;
+
+#[derive(Debug, Args)]
+pub struct LeaderboardArgs {
+    /// Id of the private leaderboard, defaults to AOC_LEADERBOARD_ID
+    id: Option<u64>,
+    /// Show the completion times of one day instead of the standings
+    #[arg(long)]
+    day: Option<usize>,
+    /// Defaults to the year of the workspace
+    #[arg(long)]
+    year: Option<usize>,
+}
+
+pub async fn run(args: LeaderboardArgs) -> Result<()> {
+    let client = AocClient::from_env();
+    let year = resolve_year(args.year)?;
+    if let Some(day) = args.day
+        && !(1..=days_in(year)).contains(&day)
+    {
+        return Err(FetchError::NoSuchDay { year, day }.into());
+    }
+    let id = match args.id {
+        Some(id) => id,
+        None => std::env::var("AOC_LEADERBOARD_ID")
+            .ok()
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(|| {
+                eyre!("Pass the leaderboard id, or set AOC_LEADERBOARD_ID to the number in its URL")
+            })?,
+    };
+
+    let leaderboard = client.leaderboard(year, id).await?;
+    match args.day {
+        Some(day) => print!("{}", leaderboard.render_day(day)),
+        None => print!("{}", leaderboard.render()),
+    }
+    Ok(())
+}
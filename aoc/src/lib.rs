@@ -0,0 +1,3 @@
+//! The parts of the `aoc` binary other crates build on, e.g. the benchmarks.
+
+pub mod registry;
@@ -0,0 +1,66 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use color_eyre::Result;
+
+mod cache;
+mod days;
+mod examples;
+mod leaderboard;
+mod perf;
+mod progress;
+mod puzzle;
+mod run;
+mod vault;
+
+/// Advent of Code helper for this workspace.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Inspect and manage the puzzle input cache
+    #[command(subcommand)]
+    Cache(cache::CacheCommand),
+    /// Fetch the puzzle pages and write their examples and answers to `day-XX/examples/`
+    Examples(examples::ExamplesArgs),
+    /// Show a private leaderboard, refreshed at most every 15 minutes
+    Leaderboard(leaderboard::LeaderboardArgs),
+    /// Compare the times of runs across commits
+    #[command(subcommand)]
+    Perf(perf::PerfCommand),
+    /// Compare the stars of the account with the state of the day crates
+    Progress(progress::ProgressArgs),
+    /// Save the puzzle text as Markdown to `day-XX/puzzle.md`
+    Puzzle(puzzle::PuzzleArgs),
+    /// Run the solutions of days against their puzzle input, e.g. `aoc run 1..=12`
+    Run(run::RunCommandArgs),
+    /// Manage the encrypted inputs and answers in `vault/`
+    #[command(subcommand)]
+    Vault(vault::VaultCommand),
+}
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Cache(command) => cache::run(command).await,
+        Command::Examples(args) => examples::run(args).await,
+        Command::Leaderboard(args) => leaderboard::run(args).await,
+        Command::Perf(command) => perf::run(command).await,
+        Command::Progress(args) => progress::run(args).await,
+        Command::Puzzle(args) => puzzle::run(args).await,
+        Command::Run(args) => return run::run(args).await,
+        Command::Vault(command) => vault::run(command).await,
+    };
+    result.map(|()| ExitCode::SUCCESS)
+}
+
+/// The year given with `--year`, or else the year of the workspace.
+fn resolve_year(year: Option<usize>) -> Result<usize> {
+    year.map_or_else(util::aoc_year, Ok)
+}
@@ -0,0 +1,70 @@
+use clap::Subcommand;
+use color_eyre::{Result, eyre::eyre};
+use util::{AocClient, PerfHistory, git_resolve, render_changes, workspace_root};
+
+use crate::resolve_year;
+
+#[derive(Debug, Subcommand)]
+pub enum PerfCommand {
+    /// Compare the latest times of each day with the times at a commit, e.g. `aoc perf diff main`
+    Diff {
+        /// The commit to compare with, e.g. a branch, `HEAD~3` or a hash
+        rev: String,
+        /// Flag the steps that got more than this many percent slower
+        #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+        threshold: f64,
+        /// Compare the runs with the input of this session profile, defaults to AOC_PROFILE
+        #[arg(long)]
+        profile: Option<String>,
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+    },
+}
+
+pub async fn run(command: PerfCommand) -> Result<()> {
+    let client = AocClient::from_env();
+    match command {
+        PerfCommand::Diff {
+            rev,
+            threshold,
+            profile,
+            year,
+        } => {
+            let year = resolve_year(year)?;
+            let base = git_resolve(&workspace_root(), &rev)?;
+            let history = PerfHistory::load(client.perf_path())?;
+            if history.skipped() > 0 {
+                println!(
+                    "Skipped {} lines of {} that are not a run",
+                    history.skipped(),
+                    client.perf_path().display()
+                );
+            }
+            let profile =
+                profile.map_or_else(|| client.profile().to_string(), |p| p.to_lowercase());
+            let changes = history.diff(year, &profile, &base);
+            if changes.is_empty() {
+                println!(
+                    "No runs at {} to compare with, check it out and run the days there first",
+                    rev
+                );
+                return Ok(());
+            }
+            print!("{}", render_changes(&changes, threshold));
+            let regressed = changes
+                .iter()
+                .filter(|change| change.is_regression(threshold))
+                .count();
+            if regressed > 0 {
+                return Err(eyre!(
+                    "{} steps got more than {}% slower than at {}",
+                    regressed,
+                    threshold,
+                    rev
+                ));
+            }
+        }
+    }
+    Ok(())
+}
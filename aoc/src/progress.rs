@@ -0,0 +1,61 @@
+use aoc::registry;
+use clap::Args;
+use color_eyre::Result;
+use util::{
+    AocClient, Implementation, Input, InputSource, examples_dir, local_progress, solver_progress,
+};
+
+use crate::resolve_year;
+
+#[derive(Debug, Args)]
+pub struct ProgressArgs {
+    /// Defaults to the year of the workspace
+    #[arg(long)]
+    year: Option<usize>,
+}
+
+pub async fn run(args: ProgressArgs) -> Result<()> {
+    let client = AocClient::from_env();
+    let year = resolve_year(args.year)?;
+    let stars = client.stars(year).await?;
+
+    println!("Day  Stars  {:<8} {:<8}", "Part 1", "Part 2");
+    for (&day, &stars) in &stars {
+        let parts = match registry::solver(day) {
+            Some(solver) => {
+                let (input, source) = progress_input(&client, year, day);
+                solver_progress(solver, &input, source, &client.day_dir(day))
+            }
+            None => local_progress(&client.day_dir(day)),
+        };
+        let mut notes = Vec::new();
+        for (part, implementation) in parts.iter().enumerate() {
+            if stars > part && *implementation != Implementation::Done {
+                notes.push(format!(
+                    "part {} solved on AoC, but {} here",
+                    part + 1,
+                    implementation
+                ));
+            }
+        }
+        println!(
+            "{:>3}  {:<5}  {:<8} {:<8} {}",
+            day,
+            "*".repeat(stars),
+            parts[0].to_string(),
+            parts[1].to_string(),
+            notes.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// What to run a registered day on to see which parts are done: its first example, else its
+/// cached puzzle input, else an empty input, which may leave it to the source.
+fn progress_input(client: &AocClient, year: usize, day: usize) -> (Input, InputSource) {
+    if let Ok(example) = std::fs::read_to_string(examples_dir(&client.day_dir(day)).join("1.txt")) {
+        return (Input::new(example), InputSource::Example);
+    }
+    let input = client.cached_input(year, day).ok().flatten();
+    (Input::new(input.unwrap_or_default()), InputSource::Puzzle)
+}
@@ -0,0 +1,22 @@
+use clap::Args;
+use color_eyre::Result;
+use util::AocClient;
+
+use crate::{days::Days, resolve_year};
+
+#[derive(Debug, Args)]
+pub struct PuzzleArgs {
+    days: Days,
+    /// Defaults to the year of the workspace
+    #[arg(long)]
+    year: Option<usize>,
+}
+
+pub async fn run(args: PuzzleArgs) -> Result<()> {
+    let client = AocClient::from_env();
+    let year = resolve_year(args.year)?;
+    for day in args.days.0 {
+        client.save_puzzle(year, day).await?;
+    }
+    Ok(())
+}
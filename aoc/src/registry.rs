@@ -0,0 +1,23 @@
+use util::Solver;
+
+/// The solution of every day `aoc` depends on, generated by `build.rs` from the `day-XX`
+/// dependencies in `Cargo.toml`, so a new day only needs its line there.
+pub const SOLVERS: &[Solver] = &include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+
+/// The solver of a day, if it has one.
+pub fn solver(day: usize) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        // in order, and each day once
+        assert!(SOLVERS.windows(2).all(|pair| pair[0].day() < pair[1].day()));
+        assert_eq!(solver(5).map(Solver::day), Some(5));
+        assert!(solver(13).is_none());
+    }
+}
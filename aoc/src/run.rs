@@ -0,0 +1,243 @@
+use std::process::ExitCode;
+
+use aoc::registry;
+use clap::Args;
+use color_eyre::{Result, eyre::eyre};
+use util::{Outcome, PartAnswer, PartError, Report, RunArgs, format_elapsed, run_solver};
+
+use crate::{days::Days, resolve_year};
+
+/// The exit code when nothing failed, but some parts still panic with `todo!()`.
+/// Failures exit with 1.
+pub const EXIT_UNIMPLEMENTED: u8 = 3;
+
+#[derive(Debug, Args)]
+pub struct RunCommandArgs {
+    /// The days to run, e.g. `5` or `1..=12`
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Option<Days>,
+    /// Run every day with a solution
+    #[arg(long)]
+    all: bool,
+    /// Defaults to the year of the workspace
+    #[arg(long)]
+    year: Option<usize>,
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+pub async fn run(args: RunCommandArgs) -> Result<ExitCode> {
+    let year = resolve_year(args.year)?;
+    let solvers = match &args.days {
+        Some(days) => days
+            .0
+            .iter()
+            .map(|&day| {
+                registry::solver(day).ok_or_else(|| {
+                    eyre!(
+                        "Day {} has no solution, add `day-{:02} = {{ path = \"../day-{:02}\" }}` to aoc/Cargo.toml",
+                        day, day, day
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => registry::SOLVERS.iter().collect(),
+    };
+    if solvers.len() > 1 && args.run.has_local_input() {
+        return Err(eyre!(
+            "An input other than the puzzle input needs a single day"
+        ));
+    }
+
+    let mut reports = Vec::new();
+    for solver in solvers {
+        reports.extend(run_solver(&args.run, year, solver).await?);
+    }
+
+    println!();
+    print!("{}", summary(&reports));
+    let parts = |report: &Report| match &report.outcome {
+        Outcome::Ran { answers, .. } => answers.iter().flatten().cloned().collect(),
+        _ => Vec::new(),
+    };
+    let failed = reports
+        .iter()
+        .filter(|report| {
+            matches!(report.outcome, Outcome::Failed(_))
+                || parts(report)
+                    .iter()
+                    .any(|part| matches!(part, Err(PartError::Panicked { .. })))
+        })
+        .count();
+    let unimplemented = reports
+        .iter()
+        .flat_map(parts)
+        .filter(|part| *part == Err(PartError::Unimplemented))
+        .count();
+    if failed > 0 {
+        return Err(eyre!("{} of {} runs failed", failed, reports.len()));
+    }
+    if unimplemented > 0 {
+        println!("Parts not implemented yet: {}", unimplemented);
+        return Ok(ExitCode::from(EXIT_UNIMPLEMENTED));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// A table of the answers of every run, and the median time of parsing and solving them,
+/// with the profile when there are several.
+fn summary(reports: &[Report]) -> String {
+    let profiles = reports.iter().any(|report| report.profile.is_some());
+    let row = |day: &str, profile: &str, time: &str, part1: &str, part2: &str| {
+        let profile = if profiles {
+            format!("{:<10} ", profile)
+        } else {
+            String::new()
+        };
+        format!(
+            "{:>3}  {}{:>8}  {:<20} {}",
+            day, profile, time, part1, part2
+        )
+        .trim_end()
+        .to_string()
+            + "\n"
+    };
+
+    let mut table = row("Day", "Profile", "Time", "Part 1", "Part 2");
+    for report in reports {
+        let day = report.day.to_string();
+        let profile = report.profile.as_deref().unwrap_or_default();
+        let line = match &report.outcome {
+            Outcome::Ran {
+                answers: [part1, part2],
+                timings,
+            } => {
+                let time = timings
+                    .median_total()
+                    .map(format_elapsed)
+                    .unwrap_or_default();
+                row(
+                    &day,
+                    profile,
+                    &time,
+                    &cell(part1.as_ref()),
+                    &cell(part2.as_ref()),
+                )
+            }
+            Outcome::Locked => row(&day, profile, "", "locked", ""),
+            Outcome::Failed(e) => row(&day, profile, "", &format!("failed: {}", e), ""),
+        };
+        table.push_str(&line);
+    }
+    table
+}
+
+fn cell(answer: Option<&PartAnswer>) -> String {
+    match answer {
+        Some(Ok(answer)) => answer.clone(),
+        // the message was printed with the answers already
+        Some(Err(PartError::Panicked { .. })) => "panicked".to_string(),
+        Some(Err(e)) => e.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use util::Timings;
+
+    fn report(day: usize, profile: Option<&str>, outcome: Outcome) -> Report {
+        Report {
+            day,
+            profile: profile.map(str::to_string),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let reports = [
+            report(
+                1,
+                None,
+                Outcome::Ran {
+                    answers: [Some(Ok("1064".to_string())), Some(Ok("6122".to_string()))],
+                    timings: Timings {
+                        input: Some(Duration::from_millis(40)),
+                        parse: vec![Duration::from_micros(20)],
+                        parts: [
+                            vec![Duration::from_micros(100)],
+                            vec![Duration::from_micros(1234)],
+                        ],
+                    },
+                },
+            ),
+            report(
+                2,
+                None,
+                Outcome::Ran {
+                    answers: [None, Some(Ok("42".to_string()))],
+                    timings: Timings::default(),
+                },
+            ),
+            report(
+                10,
+                None,
+                Outcome::Ran {
+                    answers: [
+                        Some(Ok("7".to_string())),
+                        Some(Err(PartError::Unimplemented)),
+                    ],
+                    timings: Timings::default(),
+                },
+            ),
+            report(
+                11,
+                None,
+                Outcome::Ran {
+                    answers: [
+                        Some(Err(PartError::Panicked {
+                            message: "no path from you to out".to_string(),
+                            location: None,
+                        })),
+                        None,
+                    ],
+                    timings: Timings::default(),
+                },
+            ),
+            report(12, None, Outcome::Locked),
+        ];
+        assert_eq!(
+            summary(&reports),
+            "Day      Time  Part 1               Part 2\n\
+             \x20 1     1.4ms  1064                 6122\n\
+             \x20 2                                 42\n\
+             \x2010            7                    unimplemented\n\
+             \x2011            panicked\n\
+             \x2012            locked\n"
+        );
+    }
+
+    #[test]
+    fn test_summary_profiles() {
+        let reports = [
+            report(
+                5,
+                Some("main"),
+                Outcome::Ran {
+                    answers: [Some(Ok("3".to_string())), None],
+                    timings: Timings::default(),
+                },
+            ),
+            report(5, Some("alex"), Outcome::Failed("timed out".to_string())),
+        ];
+        assert_eq!(
+            summary(&reports),
+            "Day  Profile        Time  Part 1               Part 2\n\
+             \x20 5  main                 3\n\
+             \x20 5  alex                 failed: timed out\n"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use clap::Subcommand;
+use color_eyre::Result;
+use util::{AocClient, days_in};
+
+use crate::{days::Days, resolve_year};
+
+#[derive(Debug, Subcommand)]
+pub enum VaultCommand {
+    /// Encrypt cached inputs and their correct answers into `vault/`, e.g. `aoc vault seal 1..=12`
+    Seal {
+        days: Days,
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+    },
+    /// List the days in the vault, and whether the key opens them
+    List {
+        /// Defaults to the year of the workspace
+        #[arg(long)]
+        year: Option<usize>,
+    },
+}
+
+pub async fn run(command: VaultCommand) -> Result<()> {
+    let client = AocClient::from_env();
+    match command {
+        VaultCommand::Seal { days, year } => {
+            let year = resolve_year(year)?;
+            for day in days.0 {
+                if client.seal(year, day)? {
+                    println!("Sealed year {}, day {}", year, day);
+                } else {
+                    println!(
+                        "Input for year {}, day {} is not cached, fetch it first",
+                        year, day
+                    );
+                }
+            }
+        }
+        VaultCommand::List { year } => {
+            let year = resolve_year(year)?;
+            for day in 1..=days_in(year) {
+                match client.vault_entry(year, day) {
+                    Ok(Some(entry)) => println!(
+                        "Year {}, day {}: {} bytes, answers {:?}",
+                        year,
+                        day,
+                        entry.input.len(),
+                        entry.answers
+                    ),
+                    Ok(None) => {}
+                    Err(e) => println!("Year {}, day {}: {}", year, day, e),
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::hint::black_box;
+
+use benches::bench_input;
+use criterion::{Criterion, criterion_group, criterion_main};
+use util::{AocClient, aoc_year};
+
+/// Benchmarks parsing and each part of every registered day, as groups named like
+/// `day-05/input`, or `day-05/example` when the puzzle input was never fetched.
+fn days(c: &mut Criterion) {
+    let client = AocClient::from_env();
+    let year = aoc_year().unwrap();
+    for solver in aoc::registry::SOLVERS {
+        let day = solver.day();
+        let (input, source) = match bench_input(&client, year, day) {
+            Ok(Some(input)) => input,
+            Ok(None) => {
+                eprintln!("Day {}: no cached input or example, skipping", day);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("Day {}: could not read the input, skipping: {}", day, e);
+                continue;
+            }
+        };
+        // a part that panics, e.g. with `todo!()`, would abort every benchmark after it
+        let solved = solver.solve(&input, None, source);
+        if solved.part_times.iter().all(Option::is_none) {
+            eprintln!("Day {}: parsing the {} panicked, skipping", day, source);
+            continue;
+        }
+
+        let mut group = c.benchmark_group(format!("day-{:02}/{}", day, source));
+        group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(&input))));
+        solver.with_parsed(&input, source, &mut |solve| {
+            for (i, part) in solved.parts.iter().enumerate() {
+                if let Some(Ok(_)) = part {
+                    group.bench_function(format!("part{}", i + 1), |b| {
+                        b.iter(|| solve(black_box(i + 1)))
+                    });
+                } else {
+                    eprintln!("Day {}: part {} does not run, skipping", day, i + 1);
+                }
+            }
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
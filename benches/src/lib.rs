@@ -0,0 +1,63 @@
+//! Picks what the benchmarks in `benches/days.rs` run each day against.
+
+use color_eyre::Result;
+use util::{AocClient, Input, InputSource, examples_dir};
+
+/// The input to benchmark a day on: the cached puzzle input, else the one in the vault, else
+/// the first example. Nothing is fetched, so benchmarks run offline and without a session.
+pub fn bench_input(
+    client: &AocClient,
+    year: usize,
+    day: usize,
+) -> Result<Option<(Input, InputSource)>> {
+    if let Some(input) = client.cached_input(year, day)? {
+        return Ok(Some((Input::new(input), InputSource::Puzzle)));
+    }
+    if let Some(entry) = client.vault_entry(year, day)? {
+        return Ok(Some((Input::new(entry.input), InputSource::Puzzle)));
+    }
+    let example = examples_dir(&client.day_dir(day)).join("1.txt");
+    match std::fs::read_to_string(example) {
+        Ok(example) => Ok(Some((Input::new(example), InputSource::Example))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use util::{DEFAULT_PROFILE, VaultEntry};
+
+    #[test]
+    fn test_bench_input() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new("http://localhost").with_data_dir(dir.path());
+        assert_eq!(bench_input(&client, 2025, 5)?, None);
+
+        let examples = examples_dir(&client.day_dir(5));
+        std::fs::create_dir_all(&examples)?;
+        std::fs::write(examples.join("1.txt"), "3-5\r\n\r\n1\r\n")?;
+        assert_eq!(
+            bench_input(&client, 2025, 5)?,
+            Some((Input::new("3-5\n\n1\n"), InputSource::Example))
+        );
+
+        // the puzzle input wins over the examples
+        let client = client
+            .with_vault_key("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let entry = VaultEntry {
+            input: "3-5\n10-14\n\n1\n5\n".to_string(),
+            answers: [None, None],
+        };
+        client
+            .vault()?
+            .unwrap()
+            .put(2025, 5, DEFAULT_PROFILE, &entry)?;
+        assert_eq!(
+            bench_input(&client, 2025, 5)?,
+            Some((Input::new(entry.input), InputSource::Puzzle))
+        );
+        Ok(())
+    }
+}
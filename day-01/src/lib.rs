@@ -1,5 +1,26 @@
-pub fn part1(input: &str) -> String {
-    let input_data = parse_input(input);
+use util::{Answer, Input, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<isize>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input_data: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(input_data).into()
+    }
+
+    fn part2(input_data: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(input_data).into()
+    }
+}
+
+fn part1(input_data: &[isize]) -> usize {
     let mut position: isize = 50;
     let mut times_zero = 0;
     for value in input_data {
@@ -13,11 +34,10 @@ pub fn part1(input: &str) -> String {
             times_zero += 1;
         }
     }
-    times_zero.to_string()
+    times_zero
 }
 
-pub fn part2(input: &str) -> String {
-    let input_data = parse_input(input);
+fn part2(input_data: &[isize]) -> usize {
     let mut position: isize = 50;
     let mut times_zero = 0;
     for value in input_data.iter() {
@@ -38,12 +58,14 @@ pub fn part2(input: &str) -> String {
         }
     }
 
-    times_zero.to_string()
+    times_zero
 }
 
-fn parse_input(input: &str) -> Vec<isize> {
-    let parsed_input = input.replace("L", "-").replace("R", "");
-    parsed_input
+fn parse_input(input: &Input) -> Vec<isize> {
+    input
+        .trimmed()
+        .replace("L", "-")
+        .replace("R", "")
         .lines()
         .map(|line| match line.parse::<isize>() {
             Ok(v) => v,
@@ -71,8 +93,8 @@ L82"#;
 
         let expected = r#"3"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day01::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -90,7 +112,14 @@ L82"#;
 
         let expected = r#"6"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day01::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn test_surrounding_whitespace() {
+        let input = "\nL68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n\n";
+
+        assert_eq!(Day01::solve_part1(input).to_string(), "3");
     }
 }
@@ -1,37 +1,39 @@
-pub fn part1(input: &str) -> String {
-    let ranges = input
-        .split(',')
-        .filter_map(|range| {
-            let mut parts = range.split('-');
-            let from = parts.next()?.trim().parse::<usize>().ok()?;
-            let to = parts.next()?.trim().parse::<usize>().ok()?;
-            Some((from, to))
-        })
-        .collect::<Vec<(usize, usize)>>();
-
-    ranges
-        .iter()
-        .flat_map(|(from, to)| part1::invalid_ids_in_range(*from, *to))
-        .sum::<usize>()
-        .to_string()
-}
+use util::{Answer, Input, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<(usize, usize)>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split('-');
+                let from = parts.next()?.trim().parse::<usize>().ok()?;
+                let to = parts.next()?.trim().parse::<usize>().ok()?;
+                Some((from, to))
+            })
+            .collect()
+    }
 
-pub fn part2(input: &str) -> String {
-    let ranges = input
-        .split(',')
-        .filter_map(|range| {
-            let mut parts = range.split('-');
-            let from = parts.next()?.trim().parse::<usize>().ok()?;
-            let to = parts.next()?.trim().parse::<usize>().ok()?;
-            Some((from, to))
-        })
-        .collect::<Vec<(usize, usize)>>();
-
-    ranges
-        .iter()
-        .flat_map(|(from, to)| part2::invalid_ids_in_range(*from, *to))
-        .sum::<usize>()
-        .to_string()
+    fn part1(ranges: &Self::Parsed<'_>, _: ()) -> Answer {
+        ranges
+            .iter()
+            .flat_map(|(from, to)| part1::invalid_ids_in_range(*from, *to))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(ranges: &Self::Parsed<'_>, _: ()) -> Answer {
+        ranges
+            .iter()
+            .flat_map(|(from, to)| part2::invalid_ids_in_range(*from, *to))
+            .sum::<usize>()
+            .into()
+    }
 }
 
 mod part1 {
@@ -78,9 +80,10 @@ mod tests {
     use super::*;
 
     mod test_part1 {
-        use super::part1;
+        use super::Day02;
         use super::part1::*;
         use rstest::rstest;
+        use util::Solution;
 
         #[rstest]
         #[case(11)]
@@ -132,15 +135,16 @@ mod tests {
 
             let expected = r#"1227775554"#;
 
-            let result = part1(input);
-            assert_eq!(result, expected);
+            let result = Day02::solve_part1(input);
+            assert_eq!(result.to_string(), expected);
         }
     }
 
     mod test_part2 {
-        use super::part2;
+        use super::Day02;
         use super::part2::*;
         use rstest::rstest;
+        use util::Solution;
 
         #[rstest]
         #[case(11)]
@@ -203,8 +207,8 @@ mod tests {
 
             let expected = r#"4174379265"#;
 
-            let result = part2(input);
-            assert_eq!(result, expected);
+            let result = Day02::solve_part2(input);
+            assert_eq!(result.to_string(), expected);
         }
     }
 }
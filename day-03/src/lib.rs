@@ -1,31 +1,39 @@
-pub fn part1(input: &str) -> String {
-    let max_joltage: usize = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<_>>()
-        .iter()
-        .map(|batteries| part1::max_joltage_for_bank(batteries))
-        .sum();
-    max_joltage.to_string()
-}
+use util::{Answer, Input, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// The joltage of each battery, one bank per line
+    type Parsed<'a> = Vec<Vec<usize>>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).unwrap() as usize)
+                    .collect::<Vec<usize>>()
+            })
+            .collect()
+    }
 
-pub fn part2(input: &str) -> String {
-    let max_joltage: usize = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<_>>()
-        .iter()
-        .map(|batteries| part2::max_joltage_for_bank(batteries))
-        .sum();
-    max_joltage.to_string()
+    fn part1(banks: &Self::Parsed<'_>, _: ()) -> Answer {
+        banks
+            .iter()
+            .map(|batteries| part1::max_joltage_for_bank(batteries))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(banks: &Self::Parsed<'_>, _: ()) -> Answer {
+        banks
+            .iter()
+            .map(|batteries| part2::max_joltage_for_bank(batteries))
+            .sum::<usize>()
+            .into()
+    }
 }
 
 mod part1 {
@@ -92,8 +100,10 @@ mod tests {
     use super::*;
 
     mod test_part1 {
+        use super::Day03;
         use super::part1;
         use rstest::rstest;
+        use util::Solution;
 
         #[rstest]
         #[case("987654321111111", 98)]
@@ -119,14 +129,16 @@ mod tests {
 
             let expected = r#"357"#;
 
-            let result = part1(input);
-            assert_eq!(result, expected);
+            let result = Day03::solve_part1(input);
+            assert_eq!(result.to_string(), expected);
         }
     }
 
     mod test_part2 {
+        use super::Day03;
         use super::part2;
         use rstest::rstest;
+        use util::Solution;
 
         #[rstest]
         #[case("987654321111111", 987654321111)]
@@ -152,8 +164,8 @@ mod tests {
 
             let expected = r#"3121910778619"#;
 
-            let result = part2(input);
-            assert_eq!(result, expected);
+            let result = Day03::solve_part2(input);
+            assert_eq!(result.to_string(), expected);
         }
     }
 }
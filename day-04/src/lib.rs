@@ -1,34 +1,44 @@
 use itertools::iproduct;
+use util::{Answer, Input, Solution};
 
 type Grid = Vec<Vec<char>>;
 
-pub fn part1(input: &str) -> String {
-    let grid: Grid = input.lines().map(|line| line.chars().collect()).collect();
+pub struct Day04;
 
-    let num_rolls_accessible: usize = iproduct!(0..grid[0].len(), 0..grid.len())
-        .filter(|(x, y)| grid[*y][*x] == '@')
-        .map(|(x, y)| (x as isize, y as isize))
-        .filter(|position| position_accessible(&grid, *position))
-        .count();
+impl Solution for Day04 {
+    type Parsed<'a> = Grid;
+    type Part1Params = ();
+    type Part2Params = ();
 
-    num_rolls_accessible.to_string()
-}
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, _: ()) -> Answer {
+        iproduct!(0..grid[0].len(), 0..grid.len())
+            .filter(|(x, y)| grid[*y][*x] == '@')
+            .map(|(x, y)| (x as isize, y as isize))
+            .filter(|position| position_accessible(grid, *position))
+            .count()
+            .into()
+    }
 
-pub fn part2(input: &str) -> String {
-    let mut grid: Grid = input.lines().map(|line| line.chars().collect()).collect();
+    fn part2(grid: &Self::Parsed<'_>, _: ()) -> Answer {
+        let mut grid = grid.clone();
 
-    let mut removed_rolls = 0;
-    loop {
-        let accessible_positions = get_accessible_positions(&grid);
-        removed_rolls += accessible_positions.len();
-        if accessible_positions.is_empty() {
-            break;
-        }
-        for (x, y) in accessible_positions {
-            grid[y][x] = '.';
+        let mut removed_rolls = 0;
+        loop {
+            let accessible_positions = get_accessible_positions(&grid);
+            removed_rolls += accessible_positions.len();
+            if accessible_positions.is_empty() {
+                break;
+            }
+            for (x, y) in accessible_positions {
+                grid[y][x] = '.';
+            }
         }
+        removed_rolls.into()
     }
-    removed_rolls.to_string()
 }
 
 fn get_accessible_positions(grid: &Grid) -> Vec<(usize, usize)> {
@@ -81,8 +91,8 @@ mod tests {
 
         let expected = r#"13"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day04::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -101,7 +111,7 @@ mod tests {
 
         let expected = r#"43"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day04::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -1,57 +1,70 @@
 use std::cmp::{max, min};
 
+use util::{Answer, Input, Solution};
+
 type FreshIngredientRange = (usize, usize);
 type Ingredient = usize;
 
-pub fn part1(input: &str) -> String {
-    let (fresh_ingredient_ranges, available_ingredients) = parse_input(input);
-    available_ingredients
-        .iter()
-        .filter(|ingredient| {
-            fresh_ingredient_ranges
-                .iter()
-                .any(|(range_min, range_max)| {
-                    **ingredient >= *range_min && **ingredient <= *range_max
-                })
-        })
-        .count()
-        .to_string()
-}
+pub struct Day05;
 
-pub fn part2(input: &str) -> String {
-    let (mut fresh_ingredient_ranges, _) = parse_input(input);
+impl Solution for Day05 {
+    type Parsed<'a> = (Vec<FreshIngredientRange>, Vec<Ingredient>);
+    type Part1Params = ();
+    type Part2Params = ();
 
-    fresh_ingredient_ranges.sort_by(|a, b| a.0.cmp(&b.0));
-    let mut current_index = 0;
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
 
-    while current_index < fresh_ingredient_ranges.len() {
-        let current_element = fresh_ingredient_ranges[current_index];
-        if let Some(next_element) = fresh_ingredient_ranges.get(current_index + 1) {
-            if current_element.0 <= next_element.0 && next_element.0 <= current_element.1 {
-                // ranges overlap (because they are sorted)
-                fresh_ingredient_ranges[current_index] = (
-                    min(current_element.0, next_element.0),
-                    max(current_element.1, next_element.1),
-                );
+    fn part1((fresh_ingredient_ranges, available_ingredients): &Self::Parsed<'_>, _: ()) -> Answer {
+        available_ingredients
+            .iter()
+            .filter(|ingredient| {
+                fresh_ingredient_ranges
+                    .iter()
+                    .any(|(range_min, range_max)| {
+                        **ingredient >= *range_min && **ingredient <= *range_max
+                    })
+            })
+            .count()
+            .into()
+    }
 
-                fresh_ingredient_ranges.remove(current_index + 1);
+    fn part2((fresh_ingredient_ranges, _): &Self::Parsed<'_>, _: ()) -> Answer {
+        let mut fresh_ingredient_ranges = fresh_ingredient_ranges.clone();
+
+        fresh_ingredient_ranges.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut current_index = 0;
+
+        while current_index < fresh_ingredient_ranges.len() {
+            let current_element = fresh_ingredient_ranges[current_index];
+            if let Some(next_element) = fresh_ingredient_ranges.get(current_index + 1) {
+                if current_element.0 <= next_element.0 && next_element.0 <= current_element.1 {
+                    // ranges overlap (because they are sorted)
+                    fresh_ingredient_ranges[current_index] = (
+                        min(current_element.0, next_element.0),
+                        max(current_element.1, next_element.1),
+                    );
+
+                    fresh_ingredient_ranges.remove(current_index + 1);
+                } else {
+                    current_index += 1
+                }
             } else {
-                current_index += 1
+                break;
             }
-        } else {
-            break;
         }
-    }
 
-    fresh_ingredient_ranges
-        .iter()
-        .map(|(min, max)| max - min + 1)
-        .sum::<usize>()
-        .to_string()
+        fresh_ingredient_ranges
+            .iter()
+            .map(|(min, max)| max - min + 1)
+            .sum::<usize>()
+            .into()
+    }
 }
 
-fn parse_input(input: &str) -> (Vec<FreshIngredientRange>, Vec<Ingredient>) {
-    let sections: Vec<&str> = input.split("\n\n").collect();
+fn parse_input(input: &Input) -> (Vec<FreshIngredientRange>, Vec<Ingredient>) {
+    let sections: Vec<&str> = input.sections().collect();
     let ranges_section = sections.first().unwrap_or(&"");
     let ingredients_section = sections.last().unwrap_or(&"");
 
@@ -98,8 +111,8 @@ mod tests {
 
         let expected = r#"3"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day05::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -119,7 +132,17 @@ mod tests {
 
         let expected = r#"14"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day05::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn test_part1_crlf() {
+        let input = "3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32\r\n";
+
+        let expected = r#"3"#;
+
+        let result = Day05::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -8,8 +8,30 @@ use nom::{
     sequence::pair,
 };
 use polars::prelude::*;
+use util::{Answer, Input, Solution};
 
-pub fn part1(input: &str) -> String {
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The parts read the worksheet differently, row by row or column by column
+    type Parsed<'a> = &'a Input;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &str) -> u64 {
     let (numbers, operators) = part1::parse_input(input);
 
     let numbers = vec_to_dataframe(numbers);
@@ -28,13 +50,12 @@ pub fn part1(input: &str) -> String {
             }
         })
         .sum::<u64>()
-        .to_string()
 }
 
 type Grid<T> = Vec<Vec<T>>;
 
-pub fn part2(input: &str) -> String {
-    let grid: Grid<char> = input.lines().map(|line| line.chars().collect()).collect();
+fn part2(input: &Input) -> usize {
+    let grid: Grid<char> = input.grid();
     let max_cols = grid.iter().map(|row| row.len()).max().unwrap_or(0);
 
     let mut stack: Vec<usize> = Vec::new();
@@ -58,7 +79,7 @@ pub fn part2(input: &str) -> String {
             _ => {}
         }
     }
-    sum.to_string()
+    sum
 }
 
 fn get_column(grid: &[Vec<char>], idx: usize) -> Vec<char> {
@@ -159,8 +180,8 @@ mod tests {
 
         let expected = r#"4277556"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day06::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -173,7 +194,7 @@ mod tests {
 
         let expected = r#"3263827"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day06::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
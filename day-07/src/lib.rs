@@ -1,10 +1,31 @@
 use pathfinding::prelude::count_paths;
 use std::collections::HashSet;
+use util::{Answer, Input, Solution};
 
 type Grid = Vec<Vec<char>>;
 type Position = (usize, usize);
-pub fn part1(input: &str) -> String {
-    let mut grid = parse_grid(input);
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Grid;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(grid.clone()).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(grid).into()
+    }
+}
+
+fn part1(mut grid: Grid) -> usize {
     let start_position = find_start_position(&grid);
     let mut split_starts: Vec<Position> = vec![start_position];
     let mut cache: HashSet<Position> = HashSet::new();
@@ -34,7 +55,7 @@ pub fn part1(input: &str) -> String {
         }
     }
     // print_grid(&grid);
-    split_positions.len().to_string()
+    split_positions.len()
 }
 
 fn print_grid(grid: &Grid) {
@@ -47,9 +68,8 @@ fn print_grid(grid: &Grid) {
     }
 }
 
-pub fn part2(input: &str) -> String {
-    let grid = parse_grid(input);
-    let start_position = find_start_position(&grid);
+fn part2(grid: &Grid) -> usize {
+    let start_position = find_start_position(grid);
     count_paths(
         start_position,
         |&(x, y)| {
@@ -61,11 +81,6 @@ pub fn part2(input: &str) -> String {
         },
         |&(_, y)| y >= grid.len(),
     )
-    .to_string()
-}
-
-fn parse_grid(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
 }
 
 fn find_start_position(grid: &Grid) -> Position {
@@ -101,8 +116,8 @@ mod tests {
 
         let expected = r#"21"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day07::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -127,7 +142,7 @@ mod tests {
 
         let expected = r#"40"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day07::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
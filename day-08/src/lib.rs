@@ -9,14 +9,47 @@ use nom::{
     multi::separated_list1,
 };
 use petgraph::{algo::connected_components, prelude::UnGraphMap, visit::Bfs};
+use util::{Answer, Input, Solution};
 
-pub fn part1(input: &str, n: usize) -> String {
-    let nodes = parse_input(input);
+pub struct Day08;
 
+/// How many of the shortest connections part 1 makes, fewer in the example
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connections(pub usize);
+
+impl Default for Connections {
+    fn default() -> Self {
+        Connections(1000)
+    }
+}
+
+impl Solution for Day08 {
+    type Parsed<'a> = Vec<Point3D>;
+    type Part1Params = Connections;
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(nodes: &Self::Parsed<'_>, Connections(n): Connections) -> Answer {
+        part1(nodes, n).into()
+    }
+
+    fn part2(nodes: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(nodes).into()
+    }
+
+    fn example_part1_params() -> Connections {
+        Connections(10)
+    }
+}
+
+fn part1(nodes: &[Point3D], n: usize) -> usize {
     // create a fully connected graph with distances as weights
     let mut node_distances = nodes
-        .clone()
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(a, b)| {
             let dist = euler_distance(a, b) as isize;
@@ -52,19 +85,16 @@ pub fn part1(input: &str, n: usize) -> String {
         }
         circuits.push(num_connections);
     }
-    println!("{:?}", circuits);
     circuits.sort();
     circuits.reverse();
-    circuits.iter().take(3).product::<usize>().to_string()
+    circuits.iter().take(3).product::<usize>()
 }
 
-pub fn part2(input: &str) -> String {
-    let nodes = parse_input(input);
-
+fn part2(nodes: &[Point3D]) -> usize {
     // create a fully connected graph with distances as weights
     let mut node_distances = nodes
-        .clone()
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(a, b)| {
             let dist = euler_distance(a, b) as isize;
@@ -93,8 +123,7 @@ pub fn part2(input: &str) -> String {
         last_edge = Some((a, b, dist));
     }
     let last_edge = last_edge.expect("No edges were added to the graph");
-    println!("last_edge: {:?}", last_edge);
-    (last_edge.0.0 * last_edge.1.0).to_string()
+    last_edge.0.0 * last_edge.1.0
 }
 
 fn parse_input(input: &str) -> Vec<(usize, usize, usize)> {
@@ -147,8 +176,11 @@ mod tests {
 
         let expected = r#"40"#;
 
-        let result = part1(input, 10);
-        assert_eq!(result, expected);
+        let result = Day08::part1(
+            &Day08::parse(&Input::new(input)),
+            Day08::example_part1_params(),
+        );
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -177,7 +209,7 @@ mod tests {
 
         let expected = r#"25272"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day08::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -1,21 +1,42 @@
 use geo::{Coord, Intersects, LineString, Polygon, point};
 use itertools::Itertools;
+use util::{Answer, Input, Solution};
 
 type Tile = (usize, usize);
-pub fn part1(input: &str) -> String {
-    let tiles = input
-        .lines()
-        .map(|line| {
-            let nums = line
-                .split(',')
-                .map(|num| num.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>();
-            (nums[0], nums[1])
-        })
-        .collect::<Vec<Tile>>();
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Tile>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(|line| {
+                let nums = line
+                    .split(',')
+                    .map(|num| num.parse::<usize>().unwrap())
+                    .collect::<Vec<usize>>();
+                (nums[0], nums[1])
+            })
+            .collect()
+    }
+
+    fn part1(tiles: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(tiles).into()
+    }
+
+    fn part2(tiles: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(tiles).into()
+    }
+}
+
+fn part1(tiles: &[Tile]) -> usize {
     let tile_pairs: Vec<_> = tiles
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(a, b)| (a, b, tile_area(a, b)))
         .collect();
@@ -25,21 +46,9 @@ pub fn part1(input: &str) -> String {
         .max_by(|a, b| a.2.cmp(&b.2))
         .unwrap()
         .2
-        .to_string()
 }
 
-pub fn part2(input: &str) -> String {
-    let tiles = input
-        .lines()
-        .map(|line| {
-            let nums = line
-                .split(',')
-                .map(|num| num.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>();
-            (nums[0], nums[1])
-        })
-        .collect::<Vec<Tile>>();
-
+fn part2(tiles: &[Tile]) -> usize {
     let poly = Polygon::new(
         LineString::from(
             tiles
@@ -54,13 +63,12 @@ pub fn part2(input: &str) -> String {
     );
 
     let tile_pairs: Vec<_> = tiles
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(a, b)| (a, b, tile_area(a, b)))
         .collect();
 
-    println!("Total tile pairs: {}", tile_pairs.len());
-
     let valid_tile_pairs: Vec<_> = tile_pairs
         .into_iter()
         .filter(|(a, b, _)| {
@@ -69,14 +77,11 @@ pub fn part2(input: &str) -> String {
         })
         .collect();
 
-    println!("Valid tile pairs: {}", valid_tile_pairs.len());
-
     valid_tile_pairs
         .into_iter()
         .max_by(|a, b| a.2.cmp(&b.2))
         .unwrap()
         .2
-        .to_string()
 }
 
 fn tile_area(a: Tile, b: Tile) -> usize {
@@ -113,8 +118,8 @@ mod tests {
 
         let expected = r#"50"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day09::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -131,7 +136,7 @@ mod tests {
 
         let expected = r#"24"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day09::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -5,9 +5,10 @@ use nom::{
     multi::{many0, separated_list1},
     sequence::delimited,
 };
+use util::{Answer, Input, Solution};
 
 #[derive(Debug, Eq, Clone)]
-struct Machine {
+pub struct Machine {
     indicator_lights_start: Vec<bool>,
     indicator_lights_goal: Vec<bool>,
     wiring_schematics: Vec<Vec<usize>>,
@@ -77,11 +78,30 @@ pub fn push_button(
     indicator_lights
 }
 
-pub fn part1(input: &str) -> String {
-    let (_, machines) = separated_list1(newline, Machine::parse)
-        .parse(input)
-        .unwrap();
+pub struct Day10;
 
+impl Solution for Day10 {
+    type Parsed<'a> = Vec<Machine>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        let (_, machines) = separated_list1(newline, Machine::parse)
+            .parse(input)
+            .unwrap();
+        machines
+    }
+
+    fn part1(machines: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(machines).into()
+    }
+
+    fn part2(_machines: &Self::Parsed<'_>, _: ()) -> Answer {
+        todo!("Implement part 2");
+    }
+}
+
+fn part1(machines: &[Machine]) -> usize {
     machines
         .iter()
         .map(|machine| {
@@ -107,11 +127,6 @@ pub fn part1(input: &str) -> String {
                 - 1
         })
         .sum::<usize>()
-        .to_string()
-}
-
-pub fn part2(input: &str) -> String {
-    todo!("Implement part 2");
 }
 
 #[cfg(test)]
@@ -127,8 +142,8 @@ mod tests {
 
         let expected = r#"7"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day10::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -137,7 +152,7 @@ mod tests {
 
         let expected = r#""#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day10::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -8,10 +8,32 @@ use nom::{
 };
 use pathfinding::{directed::count_paths, prelude::count_paths};
 use petgraph::{algo::all_simple_paths, prelude::DiGraphMap};
+use util::{Answer, Input, Solution};
 
-pub fn part1(input: &str) -> String {
-    let neighbours = parse_input(input);
-    let num_paths = count_paths(
+type Neighbours<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Neighbours<'a>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(neighbours: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(neighbours).into()
+    }
+
+    fn part2(neighbours: &Self::Parsed<'_>, _: ()) -> Answer {
+        part2(neighbours).into()
+    }
+}
+
+fn part1(neighbours: &Neighbours) -> usize {
+    count_paths(
         "you",
         |next| {
             if *next != "out" {
@@ -21,13 +43,10 @@ pub fn part1(input: &str) -> String {
             }
         },
         |n| *n == "out",
-    );
-    num_paths.to_string()
+    )
 }
 
-pub fn part2(input: &str) -> String {
-    let neighbours = parse_input(input);
-
+fn part2(neighbours: &Neighbours) -> usize {
     // today I learned something about graph theory
     // if you want to count the number of paths from A to B while going through point C,
     // you have to find all the paths from A to C and multiple that with all the paths from C to B
@@ -74,10 +93,10 @@ pub fn part2(input: &str) -> String {
             )
         })
         .product();
-    (p1 + p2).to_string()
+    p1 + p2
 }
 
-fn parse_input(input: &str) -> HashMap<&str, Vec<&str>> {
+fn parse_input(input: &str) -> Neighbours<'_> {
     separated_list1(newline, parse_line)
         .parse(input)
         .map(|(_, vec)| vec.into_iter().collect())
@@ -112,8 +131,8 @@ iii: out
 
         let expected = r#"5"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day11::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -135,7 +154,7 @@ hhh: out
 
         let expected = r#"2"#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day11::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -7,8 +7,29 @@ use nom::{
     sequence::separated_pair,
 };
 
-pub fn part1(input: &str) -> String {
-    let (input, (shapes, regions)) = parse_input(input).unwrap();
+use util::{Answer, Input, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = (Vec<Shape<'a>>, Vec<Region>);
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        parse_input(input).unwrap().1
+    }
+
+    fn part1((shapes, regions): &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(shapes, regions).into()
+    }
+
+    fn part2(_: &Self::Parsed<'_>, _: ()) -> Answer {
+        todo!("Implement part 2");
+    }
+}
+
+fn part1(shapes: &[Shape], regions: &[Region]) -> usize {
     let shapes_area = shapes
         .iter()
         .map(|shape| {
@@ -28,17 +49,13 @@ pub fn part1(input: &str) -> String {
         })
         .collect::<Vec<bool>>();
 
-    regions_fit.iter().filter(|r| **r).count().to_string()
-}
-
-pub fn part2(input: &str) -> String {
-    todo!("Implement part 2");
+    regions_fit.iter().filter(|r| **r).count()
 }
 
 type Shape<'a> = Vec<Vec<&'a str>>;
 
 #[derive(Debug)]
-struct Region {
+pub struct Region {
     dims: (usize, usize),
     shape_requirements: Vec<usize>,
 }
@@ -133,8 +150,8 @@ mod tests {
 
         let expected = r#"2"#;
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = Day12::solve_part1(input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
@@ -143,7 +160,7 @@ mod tests {
 
         let expected = r#""#;
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = Day12::solve_part2(input);
+        assert_eq!(result.to_string(), expected);
     }
 }
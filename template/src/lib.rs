@@ -1,9 +1,23 @@
-pub fn part1(input: &str) -> String {
-    todo!("Implement part 1");
-}
+use util::{Answer, Input, Solution};
+
+pub struct {{crate_name | pascal_case}};
+
+impl Solution for {{crate_name | pascal_case}} {
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(_parsed: &Self::Parsed<'_>, _: ()) -> Answer {
+        todo!("Implement part 1");
+    }
 
-pub fn part2(input: &str) -> String {
-    todo!("Implement part 2");
+    fn part2(_parsed: &Self::Parsed<'_>, _: ()) -> Answer {
+        todo!("Implement part 2");
+    }
 }
 
 #[cfg(test)]
@@ -12,21 +26,21 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let input = r#""#;
+        let input = util::example!(1);
 
-        let expected = r#""#;
+        let expected = util::example_answer!(1);
 
-        let result = part1(input);
-        assert_eq!(result, expected);
+        let result = {{crate_name | pascal_case}}::solve_part1(&input);
+        assert_eq!(result.to_string(), expected);
     }
 
     #[test]
     fn test_part2() {
-        let input = r#""#;
+        let input = util::example!(1);
 
-        let expected = r#""#;
+        let expected = util::example_answer!(2);
 
-        let result = part2(input);
-        assert_eq!(result, expected);
+        let result = {{crate_name | pascal_case}}::solve_part2(&input);
+        assert_eq!(result.to_string(), expected);
     }
 }
@@ -0,0 +1,91 @@
+use std::sync::LazyLock;
+
+use tokio::runtime::{Handle, Runtime};
+
+use crate::{AocClient, FetchError};
+
+/// The runtime behind every blocking call and every request, started by the first one.
+/// It lives as long as the process, so the connections of the shared HTTP clients, whose tasks
+/// run on it, outlive the runtime of any caller.
+pub(crate) static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("aoc-blocking")
+        .enable_all()
+        .build()
+        .expect("failed to start the async runtime")
+});
+
+/// Runs a future to completion, for callers that have no async runtime.
+/// Called from within one, the future runs on a thread of its own instead of panicking,
+/// which still blocks the calling thread until it is done, so async code should await instead.
+pub(crate) fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    if Handle::try_current().is_ok() {
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| RUNTIME.block_on(future))
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    } else {
+        RUNTIME.block_on(future)
+    }
+}
+
+impl AocClient {
+    /// Blocking version of [`AocClient::get_input`], sharing its cache.
+    /// Use the async version from async code, e.g. to fetch many days at once.
+    pub fn get_input_blocking(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        block_on(self.get_input(year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::test_client;
+    use color_eyre::Result;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    #[test]
+    fn test_get_input_blocking() -> Result<()> {
+        // the mock server needs a runtime of its own, the client under test does not
+        let runtime = tokio::runtime::Runtime::new()?;
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/2025/day/1/input"))
+                .respond_with(ResponseTemplate::new(200).set_body_string("L68\nR48\n"))
+                .expect(1)
+                .mount(&server),
+        );
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert_eq!(client.get_input_blocking(2025, 1)?, "L68\nR48\n");
+        assert_eq!(client.get_input_blocking(2025, 1)?, "L68\nR48\n");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_blocking_in_runtime() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/2/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("11-22\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert_eq!(client.get_input_blocking(2025, 2)?, "11-22\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{AocClient, SubmissionOutcome};
+
+/// Everything AoC has told us about the answer to a single part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartBounds {
+    /// The highest answer AoC said was too low
+    pub too_low: Option<i128>,
+    /// The lowest answer AoC said was too high
+    pub too_high: Option<i128>,
+    /// Every answer AoC rejected
+    pub wrong: BTreeSet<String>,
+    pub correct: Option<String>,
+}
+
+/// What the recorded responses say about an answer before it is submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerCheck {
+    /// Nothing rules the answer out
+    Plausible,
+    Correct,
+    /// This exact answer was already rejected
+    AlreadyWrong,
+    /// The answer is not above an answer AoC said was too low
+    TooLow {
+        bound: i128,
+    },
+    /// The answer is not below an answer AoC said was too high
+    TooHigh {
+        bound: i128,
+    },
+    /// The part is solved and the answer differs from the correct one
+    NotCorrect {
+        correct: String,
+    },
+}
+
+impl AnswerCheck {
+    /// Whether submitting the answer would certainly be rejected.
+    pub fn is_blocked(&self) -> bool {
+        !matches!(self, AnswerCheck::Plausible | AnswerCheck::Correct)
+    }
+
+    /// The outcome AoC would give for this answer, if it is already known.
+    pub fn known_outcome(&self) -> Option<SubmissionOutcome> {
+        match self {
+            AnswerCheck::Plausible => None,
+            AnswerCheck::Correct => Some(SubmissionOutcome::Correct),
+            AnswerCheck::AlreadyWrong | AnswerCheck::NotCorrect { .. } => {
+                Some(SubmissionOutcome::Wrong)
+            }
+            AnswerCheck::TooLow { .. } => Some(SubmissionOutcome::TooLow),
+            AnswerCheck::TooHigh { .. } => Some(SubmissionOutcome::TooHigh),
+        }
+    }
+}
+
+impl fmt::Display for AnswerCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerCheck::Plausible => write!(f, "plausible"),
+            AnswerCheck::Correct => write!(f, "correct"),
+            AnswerCheck::AlreadyWrong => write!(f, "already rejected by AoC"),
+            AnswerCheck::TooLow { bound } => write!(f, "too low, must be above {}", bound),
+            AnswerCheck::TooHigh { bound } => write!(f, "too high, must be below {}", bound),
+            AnswerCheck::NotCorrect { correct } => {
+                write!(f, "wrong, the correct answer is {}", correct)
+            }
+        }
+    }
+}
+
+/// The bounds for every year/day/part, stored as JSON next to `.aoc_cache`.
+pub struct Bounds {
+    path: PathBuf,
+    parts: BTreeMap<String, PartBounds>,
+}
+
+impl Bounds {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let parts = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { path, parts })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.parts)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: usize) -> Option<&PartBounds> {
+        self.parts.get(&key(year, day, part))
+    }
+
+    pub fn check(&self, year: usize, day: usize, part: usize, answer: &str) -> AnswerCheck {
+        let Some(bounds) = self.get(year, day, part) else {
+            return AnswerCheck::Plausible;
+        };
+        if let Some(correct) = &bounds.correct {
+            return if correct == answer {
+                AnswerCheck::Correct
+            } else {
+                AnswerCheck::NotCorrect {
+                    correct: correct.clone(),
+                }
+            };
+        }
+        if bounds.wrong.contains(answer) {
+            return AnswerCheck::AlreadyWrong;
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = bounds.too_low
+                && value <= bound
+            {
+                return AnswerCheck::TooLow { bound };
+            }
+            if let Some(bound) = bounds.too_high
+                && value >= bound
+            {
+                return AnswerCheck::TooHigh { bound };
+            }
+        }
+        AnswerCheck::Plausible
+    }
+
+    /// Records the response AoC gave for an answer, narrowing the bounds.
+    pub fn record(
+        &mut self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+        outcome: &SubmissionOutcome,
+    ) {
+        let bounds = self.parts.entry(key(year, day, part)).or_default();
+        let value = answer.parse::<i128>().ok();
+        match outcome {
+            SubmissionOutcome::Correct => bounds.correct = Some(answer.to_string()),
+            SubmissionOutcome::Wrong => {
+                bounds.wrong.insert(answer.to_string());
+            }
+            SubmissionOutcome::TooLow => {
+                bounds.wrong.insert(answer.to_string());
+                if let Some(value) = value {
+                    bounds.too_low = bounds.too_low.max(Some(value));
+                }
+            }
+            SubmissionOutcome::TooHigh => {
+                bounds.wrong.insert(answer.to_string());
+                if let Some(value) = value {
+                    bounds.too_high = Some(bounds.too_high.map_or(value, |b| b.min(value)));
+                }
+            }
+            SubmissionOutcome::RateLimited { .. } | SubmissionOutcome::AlreadySolved => {}
+        }
+    }
+}
+
+fn key(year: usize, day: usize, part: usize) -> String {
+    format!("{}:{}:{}", year, day, part)
+}
+
+/// Checks an answer against what AoC has said about earlier submissions for the same part.
+pub fn check_answer(year: usize, day: usize, part: usize, answer: &str) -> Result<AnswerCheck> {
+    AocClient::from_env().check_answer(year, day, part, answer)
+}
+
+/// Records the response AoC gave for an answer in `.aoc_bounds.json`.
+pub fn record_outcome(
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+    outcome: &SubmissionOutcome,
+) -> Result<()> {
+    let mut bounds = Bounds::load(AocClient::from_env().bounds_path())?;
+    bounds.record(year, day, part, answer, outcome);
+    bounds.save()
+}
+
+/// Prints a day's answer, warning if the recorded responses already rule it out.
+pub fn print_answer(year: usize, day: usize, part: usize, answer: &str) {
+    AocClient::from_env().print_answer(year, day, part, answer)
+}
+
+impl AocClient {
+    /// Checks an answer against what AoC has said about this profile's earlier submissions.
+    pub fn check_answer(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<AnswerCheck> {
+        Ok(Bounds::load(self.bounds_path())?.check(year, day, part, answer))
+    }
+
+    /// Prints a day's answer, warning if the recorded responses already rule it out.
+    pub fn print_answer(&self, year: usize, day: usize, part: usize, answer: &str) {
+        match self.check_answer(year, day, part, answer) {
+            Ok(check) if check.is_blocked() => println!(
+                "Day {}: Part {}: {:?} (do not submit: {})",
+                day, part, answer, check
+            ),
+            Ok(AnswerCheck::Correct) => {
+                println!("Day {}: Part {}: {:?} (correct)", day, part, answer)
+            }
+            Ok(_) => println!("Day {}: Part {}: {:?}", day, part, answer),
+            Err(e) => {
+                println!("Day {}: Part {}: {:?}", day, part, answer);
+                eprintln!("Failed to check answer against recorded bounds: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn bounds() -> Bounds {
+        let mut bounds = Bounds {
+            path: PathBuf::new(),
+            parts: BTreeMap::new(),
+        };
+        bounds.record(2025, 1, 1, "100", &SubmissionOutcome::TooLow);
+        bounds.record(2025, 1, 1, "90", &SubmissionOutcome::TooLow);
+        bounds.record(2025, 1, 1, "500", &SubmissionOutcome::TooHigh);
+        bounds.record(2025, 1, 1, "600", &SubmissionOutcome::TooHigh);
+        bounds.record(2025, 1, 1, "250", &SubmissionOutcome::Wrong);
+        bounds.record(2025, 1, 2, "abc", &SubmissionOutcome::Correct);
+        bounds
+    }
+
+    #[rstest]
+    #[case(1, "101", AnswerCheck::Plausible)]
+    #[case(1, "499", AnswerCheck::Plausible)]
+    #[case(1, "100", AnswerCheck::AlreadyWrong)]
+    #[case(1, "95", AnswerCheck::TooLow { bound: 100 })]
+    #[case(1, "-3", AnswerCheck::TooLow { bound: 100 })]
+    #[case(1, "550", AnswerCheck::TooHigh { bound: 500 })]
+    #[case(1, "250", AnswerCheck::AlreadyWrong)]
+    #[case(2, "abc", AnswerCheck::Correct)]
+    #[case(2, "abd", AnswerCheck::NotCorrect { correct: "abc".to_string() })]
+    fn test_check(#[case] part: usize, #[case] answer: &str, #[case] expected: AnswerCheck) {
+        assert_eq!(bounds().check(2025, 1, part, answer), expected);
+    }
+
+    #[test]
+    fn test_check_unknown_part() {
+        assert_eq!(bounds().check(2025, 2, 1, "1"), AnswerCheck::Plausible);
+    }
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("bounds.json");
+
+        let mut bounds = Bounds::load(&path)?;
+        bounds.record(2025, 3, 1, "42", &SubmissionOutcome::TooHigh);
+        bounds.save()?;
+
+        let bounds = Bounds::load(&path)?;
+        assert_eq!(
+            bounds.get(2025, 3, 1),
+            Some(&PartBounds {
+                too_high: Some(42),
+                wrong: BTreeSet::from(["42".to_string()]),
+                ..Default::default()
+            })
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,521 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+    time::SystemTime,
+};
+
+use cached::{DiskCache, IOCached};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{AocClient, DEFAULT_PROFILE, FetchError};
+
+/// Name of the on-disk input cache, kept from when inputs were cached with `#[io_cached]`.
+/// Entries written back then hold a bare string without a checksum.
+const INPUT_CACHE_NAME: &str = "GET_AOC_INPUT";
+
+/// Directory name of the input cache inside the workspace, and of the stray caches
+/// left behind in crate directories when the cache was relative to the current directory.
+pub(crate) const CACHE_DIR_NAME: &str = ".aoc_cache";
+
+/// Text AoC serves in place of an input when something is wrong with the request.
+const ERROR_BANNERS: &[&str] = &[
+    "Puzzle inputs differ by user",
+    "Please log in",
+    "Please don't repeatedly request this endpoint before it unlocks",
+    "404 Not Found",
+    "500 Internal Server Error",
+];
+
+/// sled only allows a database to be opened once, so every client sharing a cache directory
+/// has to share the same handle.
+static INPUT_CACHES: LazyLock<Mutex<HashMap<PathBuf, Arc<InputCache>>>> =
+    LazyLock::new(Default::default);
+
+/// A cached puzzle input along with the checksum it had when it was fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CachedInput {
+    pub body: String,
+    pub sha256: String,
+}
+
+impl CachedInput {
+    pub fn new(body: String) -> Self {
+        let sha256 = checksum(&body);
+        Self { body, sha256 }
+    }
+
+    pub fn is_intact(&self) -> bool {
+        checksum(&self.body) == self.sha256 && validate_input(&self.body).is_ok()
+    }
+}
+
+/// The layout `cached` writes values to disk with. It is private to `cached`, so the
+/// dependency is pinned and `test_decode_entries_of_cached` checks it against the real crate.
+#[derive(Deserialize)]
+struct StoredValue<V> {
+    value: V,
+    created_at: SystemTime,
+    #[allow(unused)]
+    version: u64,
+}
+
+/// Decodes a raw cache entry and the time it was written,
+/// upgrading entries written before checksums were stored.
+fn decode_entry(bytes: &[u8]) -> Option<(CachedInput, SystemTime)> {
+    if let Ok(stored) = rmp_serde::from_slice::<StoredValue<CachedInput>>(bytes) {
+        return Some((stored.value, stored.created_at));
+    }
+    let legacy = rmp_serde::from_slice::<StoredValue<String>>(bytes).ok()?;
+    Some((CachedInput::new(legacy.value), legacy.created_at))
+}
+
+/// Summary of a cached puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub profile: String,
+    pub year: usize,
+    pub day: usize,
+    /// Size of the input in bytes
+    pub size: usize,
+    pub fetched_at: SystemTime,
+}
+
+/// Disk cache of puzzle inputs keyed on `profile:year:day`.
+pub(crate) struct InputCache {
+    dir: PathBuf,
+    store: DiskCache<String, CachedInput>,
+}
+
+impl InputCache {
+    pub fn open(dir: &Path) -> Result<Arc<Self>, FetchError> {
+        let mut caches = INPUT_CACHES.lock().unwrap();
+        if let Some(cache) = caches.get(dir) {
+            return Ok(cache.clone());
+        }
+        let store = DiskCache::new(INPUT_CACHE_NAME)
+            .set_disk_directory(dir)
+            .set_sync_to_disk_on_cache_change(true)
+            .build()
+            .map_err(FetchError::cache)?;
+        let cache = Arc::new(Self {
+            dir: dir.to_path_buf(),
+            store,
+        });
+        caches.insert(dir.to_path_buf(), cache.clone());
+        Ok(cache)
+    }
+
+    /// Returns the cached input, or `None` when it is missing, unreadable or fails its checksum.
+    /// Broken entries are evicted so the input gets fetched again.
+    pub fn get(&self, key: &str) -> Result<Option<String>, FetchError> {
+        let raw = self
+            .store
+            .connection()
+            .get(key)
+            .map_err(FetchError::cache)?;
+        match raw.map(|bytes| decode_entry(&bytes)) {
+            None => Ok(None),
+            Some(Some((cached, _))) if cached.is_intact() => Ok(Some(cached.body)),
+            Some(_) => {
+                println!("Cached input for {} is corrupted, refetching", key);
+                self.store
+                    .cache_remove(&key.to_string())
+                    .map_err(FetchError::cache)?;
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn set(&self, key: &str, body: &str) -> Result<(), FetchError> {
+        self.store
+            .cache_set(key.to_string(), CachedInput::new(body.to_string()))
+            .map_err(FetchError::cache)?;
+        Ok(())
+    }
+
+    /// Removes an entry, returning whether it existed.
+    pub fn remove(&self, key: &str) -> Result<bool, FetchError> {
+        let removed = self
+            .store
+            .connection()
+            .remove(key)
+            .map_err(FetchError::cache)?;
+        self.store.connection().flush().map_err(FetchError::cache)?;
+        Ok(removed.is_some())
+    }
+
+    /// Every intact entry in the cache with the time it was written.
+    pub fn entries(&self) -> Vec<(String, CachedInput, SystemTime)> {
+        self.store
+            .connection()
+            .iter()
+            .flatten()
+            .filter_map(|(key, bytes)| {
+                let key = String::from_utf8(key.to_vec()).ok()?;
+                let (cached, written_at) = decode_entry(&bytes)?;
+                cached.is_intact().then_some((key, cached, written_at))
+            })
+            .collect()
+    }
+
+    /// Moves the inputs from another cache into this one, keeping entries this cache already has.
+    /// The other cache is deleted once every one of its entries made it across, and kept when
+    /// some could not be read, so nothing is lost. Returns how many inputs were added.
+    pub fn absorb(&self, other_dir: &Path) -> Result<usize, FetchError> {
+        let other = InputCache::open(other_dir)?;
+        let entries = other.entries();
+        let unreadable = other.store.connection().len() - entries.len();
+        let mut added = 0;
+        for (key, cached, _) in entries {
+            if self.get(&key)?.is_none() {
+                self.set(&key, &cached.body)?;
+                added += 1;
+            }
+        }
+        drop(other);
+        INPUT_CACHES.lock().unwrap().remove(other_dir);
+        if unreadable == 0 {
+            std::fs::remove_dir_all(other_dir)?;
+        } else {
+            println!(
+                "Kept {}, {} of its inputs could not be read",
+                other_dir.display(),
+                unreadable
+            );
+        }
+        Ok(added)
+    }
+
+    /// Moves entries cached before profiles existed, keyed on `year:day`, to the default profile.
+    pub fn upgrade_legacy_keys(&self) -> Result<(), FetchError> {
+        for (key, cached, _) in self.entries() {
+            if key.matches(':').count() == 1 {
+                self.set(&format!("{}:{}", DEFAULT_PROFILE, key), &cached.body)?;
+                self.remove(&key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the `.aoc_cache` directories left behind in the workspace by running binaries
+    /// and tests from different directories into this cache. The cache at the workspace root
+    /// is never one of them, even when this cache lives somewhere else.
+    pub fn merge_stray_caches(&self, workspace_root: &Path) -> Result<(), FetchError> {
+        let root_cache = std::fs::canonicalize(workspace_root.join(CACHE_DIR_NAME)).ok();
+        let mut candidates = Vec::new();
+        if let Ok(entries) = std::fs::read_dir(workspace_root) {
+            candidates.extend(
+                entries
+                    .flatten()
+                    .map(|entry| entry.path().join(CACHE_DIR_NAME)),
+            );
+        }
+        if let Ok(cwd) = std::env::current_dir()
+            && cwd.starts_with(workspace_root)
+        {
+            candidates.push(cwd.join(CACHE_DIR_NAME));
+        }
+
+        for candidate in candidates {
+            let canonical = std::fs::canonicalize(&candidate).ok();
+            let same_dir = canonical == std::fs::canonicalize(&self.dir).ok();
+            if same_dir
+                || canonical == root_cache
+                || !candidate.join(format!("{}_v1", INPUT_CACHE_NAME)).is_dir()
+            {
+                continue;
+            }
+            let added = self.absorb(&candidate)?;
+            println!(
+                "Merged {} inputs from {} into {}",
+                added,
+                candidate.display(),
+                self.dir.display()
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_raw(&self, key: &str, cached: CachedInput) {
+        self.store.cache_set(key.to_string(), cached).unwrap();
+    }
+}
+
+impl AocClient {
+    /// Lists the inputs in the cache for every profile, ordered by profile, year and day.
+    pub fn cached_inputs(&self) -> Result<Vec<CacheEntry>, FetchError> {
+        let mut entries: Vec<CacheEntry> = self
+            .input_cache()?
+            .entries()
+            .into_iter()
+            .filter_map(|(key, cached, fetched_at)| {
+                let (profile, year, day) = parse_key(&key)?;
+                Some(CacheEntry {
+                    profile,
+                    year,
+                    day,
+                    size: cached.body.len(),
+                    fetched_at,
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| (&a.profile, a.year, a.day).cmp(&(&b.profile, b.year, b.day)));
+        Ok(entries)
+    }
+
+    /// Returns the cached input for a day without fetching it.
+    pub fn cached_input(&self, year: usize, day: usize) -> Result<Option<String>, FetchError> {
+        self.input_cache()?.get(&self.cache_key(year, day))
+    }
+
+    /// Removes a day's input from the cache, returning whether it was cached.
+    pub fn evict(&self, year: usize, day: usize) -> Result<bool, FetchError> {
+        self.input_cache()?.remove(&self.cache_key(year, day))
+    }
+
+    /// Removes every input of this client's profile from the cache, returning how many were removed.
+    pub fn evict_all(&self) -> Result<usize, FetchError> {
+        let cache = self.input_cache()?;
+        let prefix = format!("{}:", self.profile());
+        let mut evicted = 0;
+        for (key, _, _) in cache.entries() {
+            if key.starts_with(&prefix) && cache.remove(&key)? {
+                evicted += 1;
+            }
+        }
+        Ok(evicted)
+    }
+}
+
+fn parse_key(key: &str) -> Option<(String, usize, usize)> {
+    let (profile, rest) = key.split_once(':')?;
+    let (year, day) = rest.split_once(':')?;
+    Some((profile.to_string(), year.parse().ok()?, day.parse().ok()?))
+}
+
+fn checksum(body: &str) -> String {
+    format!("{:x}", Sha256::digest(body.as_bytes()))
+}
+
+/// Checks that a response body looks like a puzzle input rather than an error page,
+/// so it is safe to cache.
+pub(crate) fn validate_input(body: &str) -> Result<(), FetchError> {
+    let invalid = |reason: &str| Err(FetchError::InvalidInput(reason.to_string()));
+    let trimmed = body.trim_start();
+    if trimmed.is_empty() {
+        return invalid("the body is empty");
+    }
+    let lowercase = trimmed.get(..100).unwrap_or(trimmed).to_lowercase();
+    if lowercase.starts_with("<!doctype") || lowercase.starts_with("<html") {
+        return invalid("the body is an HTML page");
+    }
+    if let Some(banner) = ERROR_BANNERS.iter().find(|banner| body.contains(*banner)) {
+        return invalid(&format!("the body contains {:?}", banner));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("L68\nL30\n")]
+    #[case("<<>>\n")]
+    #[case("..@@.@@@@.\n")]
+    fn test_validate_input_accepts(#[case] body: &str) {
+        assert!(validate_input(body).is_ok());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("  \n")]
+    #[case("<!DOCTYPE html>\n<html lang=\"en-us\"><head></head></html>")]
+    #[case("<html><body>Log in</body></html>")]
+    #[case("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]
+    #[case(
+        "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"
+    )]
+    fn test_validate_input_rejects(#[case] body: &str) {
+        assert!(matches!(
+            validate_input(body),
+            Err(FetchError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_cached_input_checksum() {
+        let mut cached = CachedInput::new("1,2,3\n".to_string());
+        assert!(cached.is_intact());
+        cached.body.push('4');
+        assert!(!cached.is_intact());
+    }
+
+    #[test]
+    fn test_merge_stray_caches() -> Result<(), FetchError> {
+        let root = tempfile::tempdir()?;
+        let cache_dir = root.path().join(CACHE_DIR_NAME);
+        let stray_dir = root.path().join("day-05").join(CACHE_DIR_NAME);
+        let cache = InputCache::open(&cache_dir)?;
+        cache.set("2025:1", "mine\n")?;
+        {
+            let stray = InputCache::open(&stray_dir)?;
+            stray.set("2025:1", "theirs\n")?;
+            stray.set("2025:5", "3-5\n")?;
+        }
+
+        cache.merge_stray_caches(root.path())?;
+
+        assert_eq!(cache.get("2025:1")?, Some("mine\n".to_string()));
+        assert_eq!(cache.get("2025:5")?, Some("3-5\n".to_string()));
+        assert!(!stray_dir.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_keeps_root_cache() -> Result<(), FetchError> {
+        let root = tempfile::tempdir()?;
+        let elsewhere = tempfile::tempdir()?;
+        let root_dir = root.path().join(CACHE_DIR_NAME);
+        InputCache::open(&root_dir)?.set("default:2025:1", "mine\n")?;
+        INPUT_CACHES.lock().unwrap().remove(&root_dir);
+
+        let cache = InputCache::open(elsewhere.path())?;
+        cache.merge_stray_caches(root.path())?;
+
+        assert_eq!(cache.get("default:2025:1")?, None);
+        assert!(root_dir.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_absorb_keeps_unreadable() -> Result<(), FetchError> {
+        let root = tempfile::tempdir()?;
+        let stray_dir = root.path().join("day-05").join(CACHE_DIR_NAME);
+        {
+            let stray = InputCache::open(&stray_dir)?;
+            stray.set("default:2025:5", "3-5\n")?;
+            stray
+                .store
+                .connection()
+                .insert("default:2025:6", b"not an entry".as_slice())
+                .map_err(FetchError::cache)?;
+        }
+
+        let cache = InputCache::open(&root.path().join(CACHE_DIR_NAME))?;
+        assert_eq!(cache.absorb(&stray_dir)?, 1);
+        assert_eq!(cache.get("default:2025:5")?, Some("3-5\n".to_string()));
+        assert!(stray_dir.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_legacy_entry() {
+        #[derive(Serialize)]
+        struct Legacy {
+            value: String,
+            created_at: SystemTime,
+            version: u64,
+        }
+        let bytes = rmp_serde::to_vec(&Legacy {
+            value: "1,2\n".to_string(),
+            created_at: SystemTime::now(),
+            version: 1,
+        })
+        .unwrap();
+
+        assert_eq!(
+            decode_entry(&bytes).map(|(cached, _)| cached),
+            Some(CachedInput::new("1,2\n".to_string()))
+        );
+    }
+
+    /// Entries written by `cached` itself, now and before checksums were stored.
+    #[test]
+    fn test_decode_entries_of_cached() -> Result<(), FetchError> {
+        let dir = tempfile::tempdir()?;
+        fn raw<V: Serialize + serde::de::DeserializeOwned>(
+            store: &DiskCache<String, V>,
+        ) -> Vec<u8> {
+            let bytes = store.connection().get("default:2025:1").unwrap();
+            bytes.expect("the entry was written").to_vec()
+        }
+        let store: DiskCache<String, CachedInput> = DiskCache::new("current")
+            .set_disk_directory(dir.path())
+            .build()
+            .map_err(FetchError::cache)?;
+        let cached = CachedInput::new("1,2\n".to_string());
+        store
+            .cache_set("default:2025:1".to_string(), cached.clone())
+            .map_err(FetchError::cache)?;
+        let (decoded, written_at) = decode_entry(&raw(&store)).unwrap();
+        assert_eq!(decoded, cached);
+        assert!(written_at.elapsed().unwrap_or_default().as_secs() < 60);
+
+        let legacy: DiskCache<String, String> = DiskCache::new("legacy")
+            .set_disk_directory(dir.path())
+            .build()
+            .map_err(FetchError::cache)?;
+        legacy
+            .cache_set("default:2025:1".to_string(), "1,2\n".to_string())
+            .map_err(FetchError::cache)?;
+        assert_eq!(
+            decode_entry(&raw(&legacy)).map(|(cached, _)| cached),
+            Some(cached)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_and_evict() -> Result<(), FetchError> {
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new("http://localhost").with_data_dir(dir.path());
+        let cache = client.input_cache()?;
+        cache.set("default:2025:12", "12\n")?;
+        cache.set("default:2025:2", "2\n")?;
+        cache.set("default:2024:7", "7\n")?;
+        cache.set("alex:2025:2", "other\n")?;
+
+        let listed: Vec<_> = client
+            .cached_inputs()?
+            .into_iter()
+            .map(|entry| (entry.profile, entry.year, entry.day, entry.size))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                ("alex".to_string(), 2025, 2, 6),
+                ("default".to_string(), 2024, 7, 2),
+                ("default".to_string(), 2025, 2, 2),
+                ("default".to_string(), 2025, 12, 3)
+            ]
+        );
+        assert_eq!(client.cached_input(2025, 12)?, Some("12\n".to_string()));
+
+        assert!(client.evict(2025, 12)?);
+        assert!(!client.evict(2025, 12)?);
+        assert_eq!(client.cached_input(2025, 12)?, None);
+        assert_eq!(client.evict_all()?, 2);
+        assert_eq!(client.cached_inputs()?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_legacy_keys() -> Result<(), FetchError> {
+        let dir = tempfile::tempdir()?;
+        let cache = InputCache::open(dir.path())?;
+        cache.set("2025:1", "1\n")?;
+        cache.set("alex:2025:1", "2\n")?;
+
+        cache.upgrade_legacy_keys()?;
+
+        assert_eq!(cache.get("2025:1")?, None);
+        assert_eq!(cache.get("default:2025:1")?, Some("1\n".to_string()));
+        assert_eq!(cache.get("alex:2025:1")?, Some("2\n".to_string()));
+        Ok(())
+    }
+}
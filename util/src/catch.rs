@@ -0,0 +1,104 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use thiserror::Error;
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PartError {
+    /// The part still panics with `todo!()` or `unimplemented!()`
+    #[error("unimplemented")]
+    Unimplemented,
+    #[error("panicked: {message}")]
+    Panicked {
+        message: String,
+        /// Where in the source the panic happened
+        location: Option<String>,
+    },
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Runs `f`, turning a panic into a [`PartError`] instead of unwinding further.
+/// While `f` runs the panic is not printed, its message and location end up in the error.
+pub(crate) fn catch_part<T>(f: impl FnOnce() -> T) -> Result<T, PartError> {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(|location| location.to_string()));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    CATCHING.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(false);
+    result.map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+        // the messages of `todo!()` and `unimplemented!()`, with or without an explanation
+        if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+            PartError::Unimplemented
+        } else {
+            PartError::Panicked {
+                message,
+                location: LOCATION.take(),
+            }
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_part() {
+        assert_eq!(catch_part(|| 42), Ok(42));
+        assert_eq!(
+            catch_part(|| -> usize { todo!("Implement part 2") }),
+            Err(PartError::Unimplemented)
+        );
+        assert_eq!(
+            catch_part(|| -> usize { unimplemented!() }),
+            Err(PartError::Unimplemented)
+        );
+
+        let Err(PartError::Panicked { message, location }) =
+            catch_part(|| -> usize { panic!("no path from {} to out", "you") })
+        else {
+            panic!("expected a panic");
+        };
+        assert_eq!(message, "no path from you to out");
+        assert!(location.unwrap().starts_with("util/src/catch.rs:"));
+        assert_eq!(
+            PartError::Panicked {
+                message,
+                location: None
+            }
+            .to_string(),
+            "panicked: no path from you to out"
+        );
+    }
+}
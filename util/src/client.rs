@@ -0,0 +1,469 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    DEFAULT_PROFILE, FetchError, Profile,
+    cache::{CACHE_DIR_NAME, InputCache, validate_input},
+    http::{DEFAULT_USER_AGENT, http_client},
+    profile, workspace_root,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Cache directories that have already had stray caches merged into them by this process.
+static MERGED_CACHES: LazyLock<Mutex<HashSet<PathBuf>>> = LazyLock::new(Default::default);
+
+/// Settings for talking to Advent of Code and where to keep local state.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    profile: String,
+    session: Option<String>,
+    pub(crate) data_dir: PathBuf,
+    cache_dir: Option<PathBuf>,
+    user_agent: String,
+    pub(crate) http: reqwest::Client,
+    pub(crate) min_interval: Duration,
+    pub(crate) retries: u32,
+    pub(crate) backoff: Duration,
+    pub(crate) vault_key: Option<String>,
+}
+
+impl AocClient {
+    /// Creates a client for the given base URL with the default profile, no session
+    /// and the workspace root as data directory.
+    /// Requests are at least a second apart and are retried up to 3 times, after 1, 2 and 4 seconds.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            profile: DEFAULT_PROFILE.to_string(),
+            session: None,
+            data_dir: workspace_root(),
+            cache_dir: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            http: http_client(DEFAULT_USER_AGENT),
+            min_interval: Duration::from_secs(1),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            vault_key: None,
+        }
+    }
+
+    /// Creates a client configured from the environment.
+    /// The session comes from the profile named in AOC_PROFILE, or AOC_SESSION when it is not set.
+    /// automatically reads AOC_SESSION, AOC_PROFILE, AOC_BASE_URL, AOC_CACHE_DIR, AOC_USER_AGENT
+    /// AOC_MIN_INTERVAL_MS and AOC_VAULT_KEY from .env file if present.
+    pub fn from_env() -> Self {
+        let _ = dotenvy::dotenv();
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let mut client = Self::new(base_url);
+        match std::env::var("AOC_PROFILE") {
+            Ok(name) => {
+                client.profile = name.to_lowercase();
+                client.session = profile(&client.profile).map(|profile| profile.session);
+            }
+            Err(_) => client.session = profile(DEFAULT_PROFILE).map(|profile| profile.session),
+        }
+        if let Ok(cache_dir) = std::env::var("AOC_CACHE_DIR") {
+            client = client.with_cache_dir(cache_dir);
+        }
+        if let Ok(key) = std::env::var("AOC_VAULT_KEY") {
+            client = client.with_vault_key(key);
+        }
+        if let Ok(user_agent) = std::env::var("AOC_USER_AGENT") {
+            client = client.with_user_agent(user_agent);
+        }
+        if let Some(millis) = std::env::var("AOC_MIN_INTERVAL_MS")
+            .ok()
+            .and_then(|millis| millis.parse().ok())
+        {
+            client = client.with_min_interval(Duration::from_millis(millis));
+        }
+        client
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    /// Uses the session of the given profile, caching its inputs and recording its
+    /// submissions separately from the other profiles.
+    pub fn with_profile(mut self, profile: &Profile) -> Self {
+        self.profile = profile.name.clone();
+        self.session = Some(profile.session.clone());
+        self
+    }
+
+    /// Sets the directory holding `.aoc_cache` and the submission ledger and bounds.
+    pub fn with_data_dir(mut self, data_dir: impl AsRef<Path>) -> Self {
+        self.data_dir = data_dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Overrides where inputs are cached, which defaults to `.aoc_cache` in the data directory.
+    pub fn with_cache_dir(mut self, cache_dir: impl AsRef<Path>) -> Self {
+        self.cache_dir = Some(cache_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Reads inputs missing from the cache from the vault encrypted with this key, see [`Vault`](crate::Vault).
+    pub fn with_vault_key(mut self, key: impl Into<String>) -> Self {
+        self.vault_key = Some(key.into());
+        self
+    }
+
+    /// Identifies the requests, AoC asks for a way to contact whoever runs the tool.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self.http = http_client(&self.user_agent);
+        self
+    }
+
+    /// Sets the minimum time between two requests, across every process using the same data directory.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sets how often a request failing with a server error or timeout is retried,
+    /// and the delay before the first retry, which doubles after every attempt.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.cache_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join(CACHE_DIR_NAME))
+    }
+
+    pub fn ledger_path(&self) -> PathBuf {
+        self.data_dir
+            .join(self.profile_file_name(".aoc_submissions", "jsonl"))
+    }
+
+    pub fn bounds_path(&self) -> PathBuf {
+        self.data_dir
+            .join(self.profile_file_name(".aoc_bounds", "json"))
+    }
+
+    /// The performance history of every profile, see [`PerfHistory`](crate::PerfHistory).
+    pub fn perf_path(&self) -> PathBuf {
+        self.data_dir.join(".aoc_perf.jsonl")
+    }
+
+    /// Directory of the crate solving the given day, e.g. `day-05`.
+    pub fn day_dir(&self, day: usize) -> PathBuf {
+        self.data_dir.join(format!("day-{:02}", day))
+    }
+
+    /// Answers differ between accounts, so every profile but the default gets its own files.
+    fn profile_file_name(&self, stem: &str, extension: &str) -> String {
+        if self.profile == DEFAULT_PROFILE {
+            format!("{}.{}", stem, extension)
+        } else {
+            format!("{}.{}.{}", stem, self.profile, extension)
+        }
+    }
+
+    pub(crate) fn cache_key(&self, year: usize, day: usize) -> String {
+        format!("{}:{}:{}", self.profile, year, day)
+    }
+
+    pub(crate) fn session(&self) -> Result<&str, FetchError> {
+        self.session.as_deref().ok_or_else(|| {
+            if self.profile == DEFAULT_PROFILE {
+                FetchError::MissingSession
+            } else {
+                FetchError::UnknownProfile {
+                    name: self.profile.clone(),
+                }
+            }
+        })
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Opens the input cache, merging any stray caches in the data directory into it
+    /// and upgrading entries cached before profiles existed the first time.
+    pub(crate) fn input_cache(&self) -> Result<Arc<InputCache>, FetchError> {
+        let dir = self.cache_dir();
+        let cache = InputCache::open(&dir)?;
+        if MERGED_CACHES.lock().unwrap().insert(dir) {
+            cache.merge_stray_caches(&self.data_dir)?;
+            cache.upgrade_legacy_keys()?;
+        }
+        Ok(cache)
+    }
+
+    /// Fetches the Advent of Code input for the given year and day.
+    /// Caches the input on disk to avoid redundant network requests.
+    /// Only bodies that look like a puzzle input are cached, never error pages.
+    pub async fn get_input(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        let cache = self.input_cache()?;
+        let key = self.cache_key(year, day);
+        if let Some(input) = cache.get(&key)? {
+            return Ok(input);
+        }
+        match self.vault_entry(year, day) {
+            Ok(Some(entry)) => {
+                cache.set(&key, &entry.input)?;
+                return Ok(entry.input);
+            }
+            Ok(None) => {}
+            Err(e) => println!(
+                "Could not read year {}, day {} from the vault, fetching it instead: {}",
+                year, day, e
+            ),
+        }
+
+        if self.profile == DEFAULT_PROFILE {
+            println!("Fetching input for year {}, day {}", year, day);
+        } else {
+            println!(
+                "Fetching input for year {}, day {} with profile {}",
+                year, day, self.profile
+            );
+        }
+        let response = self.get(&format!("/{}/day/{}/input", year, day)).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FetchError::from_status(status, year, day));
+        }
+        let body = response.text().await?;
+        validate_input(&body)?;
+        cache.set(&key, &body)?;
+        Ok(body)
+    }
+}
+
+/// A client for tests talking to `server` with a session, keeping its state in `data_dir`,
+/// with no interval between requests and retries after 10 and 20 milliseconds.
+#[cfg(test)]
+pub(crate) fn test_client(server: &wiremock::MockServer, data_dir: &Path) -> AocClient {
+    AocClient::new(server.uri())
+        .with_session("test-session")
+        .with_data_dir(data_dir)
+        .with_retries(2, Duration::from_millis(10))
+        .with_min_interval(Duration::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CachedInput;
+    use color_eyre::Result;
+    use reqwest::StatusCode;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    #[tokio::test]
+    async fn test_get_input_is_cached() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/1/input"))
+            .and(header("Cookie", "session=test-session"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("L68\nL30\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert_eq!(client.get_input(2025, 1).await?, "L68\nL30\n");
+        assert_eq!(client.get_input(2025, 1).await?, "L68\nL30\n");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_please_log_in() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/2/input"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        // errors are not cached, so both calls reach the server
+        for _ in 0..2 {
+            assert!(matches!(
+                client.get_input(2025, 2).await,
+                Err(FetchError::ExpiredSession)
+            ));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_not_unlocked() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/12/input"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert!(matches!(
+            client.get_input(2025, 12).await,
+            Err(FetchError::NotYetUnlocked {
+                year: 2025,
+                day: 12
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_server_error() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/3/input"))
+            .respond_with(ResponseTemplate::new(502))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert!(matches!(
+            client.get_input(2025, 3).await,
+            Err(FetchError::Http(StatusCode::BAD_GATEWAY))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_without_session() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new(server.uri()).with_data_dir(dir.path());
+
+        assert!(matches!(
+            client.get_input(2025, 1).await,
+            Err(FetchError::MissingSession)
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_unknown_profile() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let client = AocClient {
+            profile: "zoe".to_string(),
+            ..AocClient::new(server.uri()).with_data_dir(dir.path())
+        };
+
+        let error = client.get_input(2025, 1).await.unwrap_err();
+        assert!(matches!(&error, FetchError::UnknownProfile { name } if name == "zoe"));
+        assert!(error.to_string().contains("AOC_SESSION_ZOE"));
+        assert!(server.received_requests().await.unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_html_is_not_cached() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/4/input"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("<!DOCTYPE html>\n<html><body>[Log In]</body></html>"),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        for _ in 0..2 {
+            assert!(matches!(
+                client.get_input(2025, 4).await,
+                Err(FetchError::InvalidInput(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_refetches_corrupted_entry() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/5/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("3-5\n\n1\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        let mut corrupted = CachedInput::new("3-5\n\n1\n".to_string());
+        corrupted.body.truncate(2);
+        InputCache::open(&client.cache_dir())?.set_raw("default:2025:5", corrupted);
+
+        assert_eq!(client.get_input(2025, 5).await?, "3-5\n\n1\n");
+        assert_eq!(client.get_input(2025, 5).await?, "3-5\n\n1\n");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_per_profile() -> Result<()> {
+        let server = MockServer::start().await;
+        for (session, input) in [("alex-session", "1\n"), ("zoe-session", "2\n")] {
+            Mock::given(method("GET"))
+                .and(path("/2025/day/6/input"))
+                .and(header("Cookie", format!("session={}", session).as_str()))
+                .respond_with(ResponseTemplate::new(200).set_body_string(input))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let dir = tempfile::tempdir()?;
+        let client = |name: &str| {
+            test_client(&server, dir.path()).with_profile(&Profile {
+                name: name.to_string(),
+                session: format!("{}-session", name),
+            })
+        };
+
+        for _ in 0..2 {
+            assert_eq!(client("alex").get_input(2025, 6).await?, "1\n");
+            assert_eq!(client("zoe").get_input(2025, 6).await?, "2\n");
+        }
+        assert_ne!(client("alex").bounds_path(), client("zoe").bounds_path());
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use reqwest::StatusCode;
+use thiserror::Error;
+
+/// Everything that can go wrong while fetching a puzzle input.
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error(
+        "AOC_SESSION is not set: copy the `session` cookie from adventofcode.com into .env or the environment"
+    )]
+    MissingSession,
+    #[error(
+        "AOC_PROFILE names the profile {name}, which has no session: set AOC_SESSION_{}",
+        name.to_uppercase()
+    )]
+    UnknownProfile { name: String },
+    #[error(
+        "AoC did not accept the session cookie, it has probably expired: log in again and update AOC_SESSION"
+    )]
+    ExpiredSession,
+    #[error("day {day} of {year} has not been unlocked yet")]
+    NotYetUnlocked { year: usize, day: usize },
+    #[error(
+        "{year} has no day {day}, its puzzles are on days 1 to {} of December",
+        crate::days_in(*year)
+    )]
+    NoSuchDay { year: usize, day: usize },
+    #[error("AoC is rate limiting requests: wait a while before trying again")]
+    RateLimited,
+    #[error("AoC responded with something that is not a puzzle input: {0}")]
+    InvalidInput(String),
+    #[error(
+        "AoC did not return the leaderboard, check its id and that your account has joined it: {0}"
+    )]
+    InvalidLeaderboard(String),
+    #[error("could not open the input vault, check AOC_VAULT_KEY: {0}")]
+    Vault(String),
+    #[error("AoC responded with HTTP {0}")]
+    Http(StatusCode),
+    #[error("could not reach AoC: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("could not read or write the input cache: {0}")]
+    Cache(#[from] std::io::Error),
+}
+
+impl FetchError {
+    /// Classifies an unsuccessful response to an input request.
+    pub(crate) fn from_status(status: StatusCode, year: usize, day: usize) -> Self {
+        match status {
+            // AoC answers with "Puzzle inputs differ by user. Please log in to get your puzzle input."
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => FetchError::ExpiredSession,
+            StatusCode::NOT_FOUND => FetchError::NotYetUnlocked { year, day },
+            StatusCode::TOO_MANY_REQUESTS => FetchError::RateLimited,
+            status => FetchError::Http(status),
+        }
+    }
+
+    pub(crate) fn cache(error: impl std::fmt::Debug) -> Self {
+        FetchError::Cache(std::io::Error::other(format!("{:?}", error)))
+    }
+}
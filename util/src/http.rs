@@ -0,0 +1,266 @@
+use std::{
+    collections::HashMap,
+    fs::OpenOptions,
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+    sync::{LazyLock, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{RequestBuilder, Response};
+
+use crate::{AocClient, FetchError, blocking::RUNTIME};
+
+/// AoC asks automated tools to identify themselves, set AOC_USER_AGENT to add contact details.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/nagyben/adventofcode2025 util/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// File in the data directory holding the time of the last request, shared by every process.
+const LAST_REQUEST_FILE: &str = ".aoc_last_request";
+
+/// The HTTP clients of the process by user agent, so every `AocClient` shares one connection
+/// pool instead of each building its own. Requests are sent on the shared runtime, see
+/// [`AocClient::send`], so the pooled connections never belong to a runtime that is gone.
+static HTTP_CLIENTS: LazyLock<Mutex<HashMap<String, reqwest::Client>>> =
+    LazyLock::new(Default::default);
+
+/// The shared HTTP client sending this user agent. Clones share the same connection pool.
+pub(crate) fn http_client(user_agent: &str) -> reqwest::Client {
+    HTTP_CLIENTS
+        .lock()
+        .unwrap()
+        .entry(user_agent.to_string())
+        .or_insert_with(|| {
+            reqwest::Client::builder()
+                .user_agent(user_agent)
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("the HTTP client settings are valid")
+        })
+        .clone()
+}
+
+impl AocClient {
+    /// Sends an authenticated GET request for the given path.
+    /// Server errors and timeouts are retried with exponential backoff.
+    pub(crate) async fn get(&self, path: &str) -> Result<Response, FetchError> {
+        let mut attempt = 0;
+        loop {
+            let result = self.send(self.http.get(self.url(path))).await;
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(FetchError::Network(e)) => e.is_timeout() || e.is_connect(),
+                Err(_) => false,
+            };
+            if !retryable || attempt >= self.retries {
+                return result;
+            }
+            let delay = self.backoff * 2u32.pow(attempt);
+            println!("Request for {} failed, retrying in {:?}", path, delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends an authenticated POST with a form body. These are never retried, an answer that
+    /// timed out may still have been judged.
+    pub(crate) async fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, String)],
+    ) -> Result<Response, FetchError> {
+        self.send(self.http.post(self.url(path)).form(form)).await
+    }
+
+    /// Sends a request on the shared runtime, whatever runtime the caller is on, because the
+    /// connection it opens is pooled for the callers on every other runtime too.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, FetchError> {
+        let request = request.header("Cookie", format!("session={}", self.session()?));
+        self.wait_for_turn().await?;
+        let response = RUNTIME
+            .spawn(request.send())
+            .await
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()));
+        Ok(response?)
+    }
+
+    /// Waits until the minimum interval has passed since the last request of any process
+    /// sharing this data directory.
+    async fn wait_for_turn(&self) -> Result<(), FetchError> {
+        if self.min_interval.is_zero() {
+            return Ok(());
+        }
+        let path = self.data_dir.join(LAST_REQUEST_FILE);
+        let min_interval = self.min_interval;
+        tokio::task::spawn_blocking(move || wait_for_turn(&path, min_interval))
+            .await
+            .map_err(FetchError::cache)??;
+        Ok(())
+    }
+}
+
+fn wait_for_turn(path: &Path, min_interval: Duration) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    // held until the file is dropped, so other processes wait for us to record our request
+    file.lock()?;
+
+    let mut last = String::new();
+    file.read_to_string(&mut last)?;
+    if let Ok(last) = last.trim().parse::<u64>() {
+        let next = UNIX_EPOCH + Duration::from_millis(last) + min_interval;
+        if let Ok(wait) = next.duration_since(SystemTime::now()) {
+            std::thread::sleep(wait);
+        }
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    write!(file, "{}", now)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::client::test_client;
+    use color_eyre::Result;
+    use reqwest::StatusCode;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    #[tokio::test]
+    async fn test_get_retries_server_errors() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(header("User-Agent", DEFAULT_USER_AGENT))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+
+        let response = test_client(&server, dir.path()).get("/2025/day/1").await?;
+        assert_eq!(response.text().await?, "ok");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_gives_up_after_retries() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+
+        let response = test_client(&server, dir.path()).get("/2025/day/1").await?;
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_does_not_retry_client_errors() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/25"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+
+        let response = test_client(&server, dir.path()).get("/2025/day/25").await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_throttled() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let interval = Duration::from_millis(200);
+        let client = test_client(&server, dir.path()).with_min_interval(interval);
+        // a second client, like another process, shares the last request time through the data directory
+        let other = client.clone().with_user_agent("other");
+
+        let start = Instant::now();
+        client.get("/2025/day/1").await?;
+        other.get("/2025/day/2").await?;
+        client.get("/2025/day/3").await?;
+        assert!(start.elapsed() >= interval * 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_client_outlives_runtimes() -> Result<()> {
+        // the mock server needs a runtime of its own, which outlives the callers'
+        let runtime = tokio::runtime::Runtime::new()?;
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string("ok")
+                        .set_delay(Duration::from_millis(5)),
+                )
+                .mount(&server),
+        );
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path()).with_user_agent("outlives runtimes");
+
+        // like tests running side by side, each with a runtime that is gone once it returns,
+        // while the connections it opened are pooled for the others
+        std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| -> Result<()> {
+                        for _ in 0..25 {
+                            let body = tokio::runtime::Builder::new_current_thread()
+                                .enable_all()
+                                .build()?
+                                .block_on(async {
+                                    client
+                                        .get("/2025/day/1")
+                                        .await?
+                                        .text()
+                                        .await
+                                        .map_err(FetchError::from)
+                                })?;
+                            assert_eq!(body, "ok");
+                        }
+                        Ok(())
+                    })
+                })
+                .collect();
+            threads
+                .into_iter()
+                .try_for_each(|thread| thread.join().unwrap())
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use std::{fmt, ops::Deref};
+
+/// A puzzle input with its line endings normalized to `\n`, so CRLF files parse like the real input.
+/// The text is otherwise kept as it is, [`Input::trimmed`] strips surrounding whitespace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(text: impl AsRef<str>) -> Self {
+        let text = text.as_ref();
+        let text = if text.contains('\r') {
+            text.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            text.to_string()
+        };
+        Self { text }
+    }
+
+    /// The whole input, including leading and trailing whitespace.
+    pub fn untrimmed(&self) -> &str {
+        &self.text
+    }
+
+    /// The input without leading and trailing whitespace.
+    pub fn trimmed(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// The lines of the input, without the trailing newline.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// The blocks of the input separated by blank lines, e.g. rules followed by updates.
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split("\n\n")
+            .map(|section| section.trim_matches('\n'))
+            .filter(|section| !section.is_empty())
+    }
+
+    /// The characters of the input, one row per line.
+    pub fn grid(&self) -> Vec<Vec<char>> {
+        self.lines().map(|line| line.chars().collect()).collect()
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("3-5\n10-14\n\n1\n5\n")]
+    #[case("3-5\r\n10-14\r\n\r\n1\r\n5\r\n")]
+    #[case("3-5\r10-14\r\r1\r5\r")]
+    fn test_line_endings(#[case] text: &str) {
+        let input = Input::new(text);
+        assert_eq!(input.untrimmed(), "3-5\n10-14\n\n1\n5\n");
+        assert_eq!(input.trimmed(), "3-5\n10-14\n\n1\n5");
+        assert_eq!(
+            input.lines().collect::<Vec<_>>(),
+            ["3-5", "10-14", "", "1", "5"]
+        );
+        assert_eq!(input.sections().collect::<Vec<_>>(), ["3-5\n10-14", "1\n5"]);
+    }
+
+    #[rstest]
+    #[case("a\n\n\nb\n\n", &["a", "b"])]
+    #[case("\n\na\nb", &["a\nb"])]
+    #[case("", &[])]
+    fn test_sections(#[case] text: &str, #[case] expected: &[&str]) {
+        assert_eq!(Input::new(text).sections().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(
+            Input::new("..@\r\n@.@\r\n").grid(),
+            vec![vec!['.', '.', '@'], vec!['@', '.', '@']]
+        );
+    }
+}
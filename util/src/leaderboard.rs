@@ -0,0 +1,348 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocClient, FetchError, unlock_time};
+
+/// AoC asks not to fetch a private leaderboard more often than every 15 minutes.
+pub const LEADERBOARD_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    #[serde(default = "default_num_days")]
+    pub num_days: usize,
+    pub members: BTreeMap<u64, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for members who stay anonymous
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: u64,
+    /// Unix time of the member's latest star, 0 without any
+    pub last_star_ts: u64,
+    /// The stars of each day the member solved at least part 1 of
+    pub completion_day_level: BTreeMap<usize, DayStars>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayStars {
+    #[serde(rename = "1")]
+    pub part1: Star,
+    #[serde(rename = "2")]
+    pub part2: Option<Star>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Star {
+    /// Unix time the star was earned
+    pub get_star_ts: u64,
+    pub star_index: u64,
+}
+
+/// The leaderboard response kept on disk, to honour [`LEADERBOARD_REFRESH`] across runs.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLeaderboard {
+    fetched_at: u64,
+    body: String,
+}
+
+/// Calendars before 2025 had 25 days and no `num_days` field.
+fn default_num_days() -> usize {
+    25
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, FetchError> {
+        serde_json::from_str(json).map_err(|e| FetchError::InvalidLeaderboard(e.to_string()))
+    }
+
+    pub fn year(&self) -> usize {
+        self.event.parse().unwrap_or_default()
+    }
+
+    /// Members by local score, ties going to whoever got their last star first.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+
+    /// Renders the standings with a column per day: `*` for both stars, `+` for part 1 only.
+    pub fn render(&self) -> String {
+        let mut table = format!("{:>4} {:>5} {:>5}  {:<24} ", "", "Score", "Stars", "Name");
+        for day in 1..=self.num_days {
+            table.push_str(&format!("{:>2}", day % 10));
+        }
+        table.push('\n');
+        for (rank, member) in self.ranked().into_iter().enumerate() {
+            let _ = write!(
+                table,
+                "{:>3}) {:>5} {:>5}  {:<24} ",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.display_name()
+            );
+            for day in 1..=self.num_days {
+                let stars = match member.completion_day_level.get(&day) {
+                    Some(DayStars { part2: Some(_), .. }) => '*',
+                    Some(_) => '+',
+                    None => '.',
+                };
+                let _ = write!(table, " {}", stars);
+            }
+            table.push('\n');
+        }
+        table
+    }
+
+    /// Renders how long after the unlock each member solved both parts of a day,
+    /// and how long part 2 took after part 1, fastest first.
+    pub fn render_day(&self, day: usize) -> String {
+        // without an unlock time nobody can have solved the day, so the table stays empty
+        let unlock = unlock_time(self.year(), day)
+            .unwrap_or(UNIX_EPOCH)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let mut solved: Vec<(&Member, &DayStars)> = self
+            .members
+            .values()
+            .filter_map(|member| Some((member, member.completion_day_level.get(&day)?)))
+            .collect();
+        solved.sort_by_key(|(member, stars)| {
+            (
+                stars
+                    .part2
+                    .as_ref()
+                    .map_or(u64::MAX, |star| star.get_star_ts),
+                stars.part1.get_star_ts,
+                member.id,
+            )
+        });
+
+        let mut table = format!(
+            "Day {}\n{:>4} {:<24} {:>9} {:>9} {:>9}\n",
+            day, "", "Name", "Part 1", "Part 2", "Delta"
+        );
+        for (rank, (member, stars)) in solved.into_iter().enumerate() {
+            let part1 = stars.part1.get_star_ts;
+            let (part2, delta) = match &stars.part2 {
+                Some(star) => (
+                    format_duration(star.get_star_ts.saturating_sub(unlock)),
+                    format_duration(star.get_star_ts.saturating_sub(part1)),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            let _ = writeln!(
+                table,
+                "{:>3}) {:<24} {:>9} {:>9} {:>9}",
+                rank + 1,
+                member.display_name(),
+                format_duration(part1.saturating_sub(unlock)),
+                part2,
+                delta,
+            );
+        }
+        table
+    }
+}
+
+impl Member {
+    /// The member's name, or how AoC shows anonymous members.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+/// Formats seconds as `HH:MM:SS`, with hours going past 24.
+fn format_duration(secs: u64) -> String {
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl AocClient {
+    /// Where a private leaderboard is kept between fetches.
+    pub fn leaderboard_path(&self, year: usize, id: u64) -> PathBuf {
+        self.data_dir
+            .join(format!(".aoc_leaderboard.{}.{}.json", year, id))
+    }
+
+    /// Fetches a private leaderboard the session has access to.
+    /// A copy fetched less than [`LEADERBOARD_REFRESH`] ago is used instead of asking AoC again.
+    pub async fn leaderboard(&self, year: usize, id: u64) -> Result<Leaderboard, FetchError> {
+        self.leaderboard_at(year, id, SystemTime::now()).await
+    }
+
+    async fn leaderboard_at(
+        &self,
+        year: usize,
+        id: u64,
+        now: SystemTime,
+    ) -> Result<Leaderboard, FetchError> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let path = self.leaderboard_path(year, id);
+        let cached: Option<CachedLeaderboard> = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+        if let Some(cached) = cached
+            && now.saturating_sub(cached.fetched_at) < LEADERBOARD_REFRESH.as_secs()
+        {
+            return Leaderboard::parse(&cached.body);
+        }
+
+        println!("Fetching private leaderboard {} for year {}", id, year);
+        let response = self
+            .get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FetchError::Http(status));
+        }
+        let body = response.text().await?;
+        // AoC answers with the HTML leaderboard page when the session may not view this one
+        let leaderboard = Leaderboard::parse(&body)?;
+        let cached = CachedLeaderboard {
+            fetched_at: now,
+            body,
+        };
+        std::fs::write(
+            &path,
+            serde_json::to_string(&cached).map_err(FetchError::cache)?,
+        )?;
+        Ok(leaderboard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_client;
+    use color_eyre::Result;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    const FIXTURE: &str = include_str!("../fixtures/leaderboard.json");
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let leaderboard = Leaderboard::parse(FIXTURE)?;
+        assert_eq!(leaderboard.year(), 2025);
+        assert_eq!(leaderboard.num_days, 12);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let ben = &leaderboard.members[&1001];
+        assert_eq!(ben.name.as_deref(), Some("Ben"));
+        assert_eq!(ben.local_score, 11);
+        assert_eq!(
+            ben.completion_day_level[&2].part2,
+            Some(Star {
+                get_star_ts: 1764652500,
+                star_index: 202
+            })
+        );
+        assert_eq!(
+            leaderboard.members[&1002].completion_day_level[&2].part2,
+            None
+        );
+        assert_eq!(
+            leaderboard
+                .ranked()
+                .iter()
+                .map(|member| member.id)
+                .collect::<Vec<_>>(),
+            [1001, 1002, 1003]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_html() {
+        assert!(matches!(
+            Leaderboard::parse("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err(FetchError::InvalidLeaderboard(_))
+        ));
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let leaderboard = Leaderboard::parse(FIXTURE)?;
+        assert_eq!(
+            leaderboard.render(),
+            "     Score Stars  Name                      1 2 3 4 5 6 7 8 9 0 1 2\n\
+             \x20 1)    11     4  Ben                       * * . . . . . . . . . .\n\
+             \x20 2)    10     3  Alex                      * + . . . . . . . . . .\n\
+             \x20 3)     0     0  (anonymous user #1003)    . . . . . . . . . . . .\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_day() -> Result<()> {
+        let leaderboard = Leaderboard::parse(FIXTURE)?;
+        assert_eq!(
+            leaderboard.render_day(1),
+            "Day 1\n\
+             \x20    Name                        Part 1    Part 2     Delta\n\
+             \x20 1) Ben                       00:10:00  00:20:00  00:10:00\n\
+             \x20 2) Alex                      00:05:00  01:05:00  01:00:00\n"
+        );
+        assert_eq!(
+            leaderboard.render_day(2),
+            "Day 2\n\
+             \x20    Name                        Part 1    Part 2     Delta\n\
+             \x20 1) Ben                       00:06:40  00:15:00  00:08:20\n\
+             \x20 2) Alex                      00:05:00         -         -\n"
+        );
+        assert_eq!(
+            leaderboard.render_day(40),
+            "Day 40\n\
+             \x20    Name                        Part 1    Part 2     Delta\n"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_is_cached() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/leaderboard/private/view/1001.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FIXTURE))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+        let start = SystemTime::now();
+
+        let leaderboard = client.leaderboard_at(2025, 1001, start).await?;
+        assert_eq!(leaderboard, Leaderboard::parse(FIXTURE)?);
+        // within the refresh interval the saved copy is used
+        let later = start + LEADERBOARD_REFRESH - Duration::from_secs(1);
+        assert_eq!(client.leaderboard_at(2025, 1001, later).await?, leaderboard);
+        // after it, AoC is asked again
+        let stale = start + LEADERBOARD_REFRESH;
+        assert_eq!(client.leaderboard_at(2025, 1001, stale).await?, leaderboard);
+        Ok(())
+    }
+}
@@ -1,44 +1,66 @@
-use cached::proc_macro::io_cached;
-use color_eyre::{Result, eyre::eyre};
+use color_eyre::Result;
+
+mod blocking;
+mod bounds;
+mod cache;
+mod catch;
+mod client;
+mod error;
+mod http;
+mod input;
+mod leaderboard;
+mod perf;
+mod profile;
+mod progress;
+mod puzzle;
+mod runner;
+mod solution;
+mod submit;
+mod timing;
+mod unlock;
+mod vault;
+mod workspace;
+
+pub use bounds::{AnswerCheck, Bounds, PartBounds, check_answer, print_answer, record_outcome};
+pub use cache::CacheEntry;
+pub use catch::PartError;
+pub use client::{AocClient, DEFAULT_BASE_URL};
+pub use error::FetchError;
+pub use http::DEFAULT_USER_AGENT;
+pub use input::Input;
+pub use leaderboard::{DayStars, LEADERBOARD_REFRESH, Leaderboard, Member, Star};
+pub use perf::{PerfChange, PerfEntry, PerfHistory, Step, git_head, git_resolve, render_changes};
+pub use profile::{DEFAULT_PROFILE, Profile, profile, profiles};
+pub use progress::{Implementation, local_progress, parse_calendar, part_status, solver_progress};
+pub use puzzle::{
+    PuzzleExamples, examples_dir, extract_examples, puzzle_markdown, puzzle_path, read_example,
+    read_example_answer, write_examples,
+};
+pub use runner::{Outcome, PartAnswer, Report, RunArgs, run_solver};
+pub use solution::{Answer, InputSource, PartResult, Solution, SolvePart, Solved, Solver};
+pub use submit::{
+    Ledger, LedgerEntry, SubmissionOutcome, parse_submission_response, submit_answer,
+};
+pub use timing::{Spread, Timings, format_elapsed};
+pub use unlock::{Clock, SystemClock, days_in, unlock_time};
+pub use vault::{VAULT_DIR_NAME, Vault, VaultEntry};
+pub use workspace::{aoc_year, workspace_root};
 
 /// Fetches the Advent of Code input for the given year and day.
 /// Caches the input on disk to avoid redundant network requests.
 /// Requires the AOC_SESSION environment variable to be set with a valid session cookie.
 /// automatically reads AOC_SESSION from .env file if present.
-#[io_cached(
-    disk = true,
-    disk_dir = "./.aoc_cache",
-    map_error = r##"|e| eyre!(format!("{:?}", e))"##,
-    convert = r##"{ format!("{}:{}", year, day) }"##,
-    key = "String",
-    sync_to_disk_on_cache_change = true
-)]
-pub async fn get_aoc_input(year: usize, day: usize) -> Result<String> {
-    println!("Fetching input for year {}, day {}", year, day);
-    let _ = dotenvy::dotenv();
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "https://adventofcode.com/{}/day/{}/input",
-            year, day
-        ))
-        .header(
-            "Cookie",
-            format!(
-                "session={}",
-                std::env::var("AOC_SESSION").expect("AOC_SESSION not set")
-            ),
-        )
-        .header("Content-Type", "text/plain")
-        .send()
-        .await?;
+/// Set AOC_BASE_URL to talk to a different server than adventofcode.com.
+pub async fn get_aoc_input(year: usize, day: usize) -> Result<String, FetchError> {
+    AocClient::from_env().get_input(year, day).await
+}
 
-    let status = response.status();
-    let body = response.text().await?;
-    if !status.is_success() {
-        return Err(eyre!("Failed to fetch input: HTTP {}: {}", status, body));
-    }
-    Ok(body)
+/// Blocking version of [`get_aoc_input`], for code without an async runtime.
+/// Shares the same cache, so inputs fetched either way are only downloaded once.
+pub fn input(year: usize, day: usize) -> Result<Input, FetchError> {
+    AocClient::from_env()
+        .get_input_blocking(year, day)
+        .map(Input::new)
 }
 
 #[cfg(test)]
@@ -46,6 +68,7 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "hits the live AoC site and needs AOC_SESSION"]
     async fn test_get_aoc_input() -> Result<()> {
         let result = get_aoc_input(2024, 1).await?;
         println!("{:?}", result);
@@ -0,0 +1,482 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AocClient, DEFAULT_PROFILE, PartAnswer, Spread, Timings, format_elapsed, submit::unix_now,
+};
+
+/// The times of one run of a day against its puzzle input, as kept in the history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerfEntry {
+    /// The session profile whose input ran, as inputs differ between accounts
+    #[serde(default = "default_profile")]
+    pub profile: String,
+    pub year: usize,
+    pub day: usize,
+    /// The commit checked out, `None` outside a git repository
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes
+    pub dirty: bool,
+    /// Whether the solution was built with optimizations
+    pub release: bool,
+    /// How often the solution ran, the times are the medians over these runs
+    pub runs: usize,
+    pub parse: Duration,
+    /// `None` for parts that did not run or did not give an answer
+    pub parts: [Option<Duration>; 2],
+    /// Seconds since the unix epoch
+    pub recorded_at: u64,
+}
+
+fn default_profile() -> String {
+    DEFAULT_PROFILE.to_string()
+}
+
+impl PerfEntry {
+    /// How long a step took, `None` for parts without a time.
+    pub fn time(&self, step: Step) -> Option<Duration> {
+        match step {
+            Step::Parse => Some(self.parse),
+            Step::Part(part) => self.parts.get(part.checked_sub(1)?).copied().flatten(),
+        }
+    }
+}
+
+/// Append-only record of how long every run against a puzzle input took, stored as JSON lines.
+pub struct PerfHistory {
+    path: PathBuf,
+    entries: Vec<PerfEntry>,
+    /// Lines that could not be read, e.g. one cut short by an interrupted run
+    skipped: usize,
+}
+
+/// A step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part(usize),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// How the time of a step changed since a commit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfChange {
+    pub day: usize,
+    pub release: bool,
+    pub step: Step,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl PerfChange {
+    /// How much slower the step got in percent, negative when it got faster.
+    pub fn percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+
+    /// Whether the step got more than `threshold` percent slower.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+impl PerfHistory {
+    /// Reads the history, skipping the lines that are not an entry.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut entries = Vec::new();
+        let mut skipped = 0;
+        match std::fs::File::open(&path) {
+            Ok(file) => {
+                for line in BufReader::new(file).lines() {
+                    match serde_json::from_str(&line?) {
+                        Ok(entry) => entries.push(entry),
+                        Err(_) => skipped += 1,
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        Ok(Self {
+            path,
+            entries,
+            skipped,
+        })
+    }
+
+    pub fn entries(&self) -> &[PerfEntry] {
+        &self.entries
+    }
+
+    /// How many lines [`PerfHistory::load`] skipped.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    pub fn append(&mut self, entry: PerfEntry) -> Result<()> {
+        Self::append_to(&self.path, &entry)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Appends an entry to the history at `path` without reading the rest of it.
+    /// A last line cut short gets ended first, so it does not take the entry down with it.
+    pub fn append_to(path: impl AsRef<Path>, entry: &PerfEntry) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+        let len = file.metadata()?.len();
+        if len > 0 {
+            let mut last = [0];
+            file.seek(SeekFrom::Start(len - 1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                writeln!(file)?;
+            }
+        }
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    /// Compares the latest run of each day of a year with the runs at the `base` commit,
+    /// using the median of those, for the input of one profile. Runs with uncommitted changes
+    /// do not count for `base`, and debug builds are only compared with debug builds.
+    pub fn diff(&self, year: usize, profile: &str, base: &str) -> Vec<PerfChange> {
+        let mut runs: BTreeMap<(usize, bool), Vec<&PerfEntry>> = BTreeMap::new();
+        for entry in self
+            .entries
+            .iter()
+            .filter(|entry| entry.year == year && entry.profile == profile)
+        {
+            runs.entry((entry.day, entry.release))
+                .or_default()
+                .push(entry);
+        }
+
+        let mut changes = Vec::new();
+        for ((day, release), runs) in runs {
+            let Some(latest) = runs.last() else {
+                continue;
+            };
+            let at_base: Vec<&PerfEntry> = runs
+                .iter()
+                .copied()
+                .filter(|entry| entry.commit.as_deref() == Some(base) && !entry.dirty)
+                .collect();
+            for step in [Step::Parse, Step::Part(1), Step::Part(2)] {
+                let before: Vec<Duration> = at_base
+                    .iter()
+                    .filter_map(|entry| entry.time(step))
+                    .collect();
+                if let (Some(before), Some(after)) = (Spread::new(&before), latest.time(step))
+                    && !before.median.is_zero()
+                {
+                    changes.push(PerfChange {
+                        day,
+                        release,
+                        step,
+                        before: before.median,
+                        after,
+                    });
+                }
+            }
+        }
+        changes
+    }
+}
+
+/// Renders the changes as a table, marking the steps that got more than `threshold` percent
+/// slower.
+pub fn render_changes(changes: &[PerfChange], threshold: f64) -> String {
+    let mut table = format!(
+        "{:>3}  {:<7} {:<7} {:>9} {:>9} {:>8}\n",
+        "Day", "Step", "Build", "Before", "After", "Change"
+    );
+    for change in changes {
+        let _ = write!(
+            table,
+            "{:>3}  {:<7} {:<7} {:>9} {:>9} {:>+7.1}%",
+            change.day,
+            change.step.to_string(),
+            if change.release { "release" } else { "debug" },
+            format_elapsed(change.before),
+            format_elapsed(change.after),
+            change.percent()
+        );
+        if change.is_regression(threshold) {
+            table.push_str("  regressed");
+        }
+        table.push('\n');
+    }
+    table
+}
+
+impl AocClient {
+    /// Appends the median times of a run against the puzzle input to the performance history,
+    /// with the commit checked out in the data directory. Parts without an answer are left out,
+    /// and nothing is recorded when no part gave one.
+    pub fn record_perf(
+        &self,
+        year: usize,
+        day: usize,
+        answers: &[Option<PartAnswer>; 2],
+        timings: &Timings,
+    ) -> Result<bool> {
+        let parts = [0, 1].map(|i| {
+            Spread::new(&timings.parts[i])
+                .filter(|_| matches!(answers[i], Some(Ok(_))))
+                .map(|spread| spread.median)
+        });
+        let Some(parse) = Spread::new(&timings.parse) else {
+            return Ok(false);
+        };
+        if parts.iter().all(Option::is_none) {
+            return Ok(false);
+        }
+
+        let head = git_head(&self.data_dir);
+        PerfHistory::append_to(
+            self.perf_path(),
+            &PerfEntry {
+                profile: self.profile().to_string(),
+                year,
+                day,
+                dirty: head.as_ref().is_some_and(|(_, dirty)| *dirty),
+                commit: head.map(|(commit, _)| commit),
+                release: !cfg!(debug_assertions),
+                runs: timings.runs(),
+                parse: parse.median,
+                parts,
+                recorded_at: unix_now(),
+            },
+        )?;
+        Ok(true)
+    }
+}
+
+/// The commit checked out in `dir`, and whether the working tree has uncommitted changes.
+/// `None` outside a git repository, or without git.
+pub fn git_head(dir: &Path) -> Option<(String, bool)> {
+    let commit = git(dir, &["rev-parse", "--verify", "HEAD"]).ok()?;
+    let status = git(dir, &["status", "--porcelain", "--untracked-files=no"]).ok()?;
+    Some((commit, !status.is_empty()))
+}
+
+/// The full hash of the commit a revision names, e.g. `main`, `HEAD~3` or a short hash.
+pub fn git_resolve(dir: &Path, rev: &str) -> Result<String> {
+    git(
+        dir,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", rev)],
+    )
+    .map_err(|e| eyre!("Could not resolve {}: {}", rev, e))
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").arg("-C").arg(dir).args(args).output()?;
+    if !output.status.success() {
+        return Err(eyre!("{}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(day: usize, commit: &str, dirty: bool, micros: [u64; 3]) -> PerfEntry {
+        PerfEntry {
+            profile: DEFAULT_PROFILE.to_string(),
+            year: 2025,
+            day,
+            commit: Some(commit.to_string()),
+            dirty,
+            release: true,
+            runs: 1,
+            parse: Duration::from_micros(micros[0]),
+            parts: [
+                Some(Duration::from_micros(micros[1])),
+                Some(Duration::from_micros(micros[2])).filter(|time| !time.is_zero()),
+            ],
+            recorded_at: 100,
+        }
+    }
+
+    #[test]
+    fn test_history() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("perf.jsonl");
+
+        let mut history = PerfHistory::load(&path)?;
+        history.append(entry(1, "aaa", false, [10, 20, 30]))?;
+        history.append(entry(1, "bbb", true, [10, 20, 30]))?;
+
+        let history = PerfHistory::load(&path)?;
+        assert_eq!(
+            history.entries(),
+            [
+                entry(1, "aaa", false, [10, 20, 30]),
+                entry(1, "bbb", true, [10, 20, 30])
+            ]
+        );
+        assert_eq!(history.skipped(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_with_broken_line() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("perf.jsonl");
+        PerfHistory::append_to(&path, &entry(1, "aaa", false, [10, 20, 30]))?;
+        // a run interrupted while writing its entry
+        let mut file = OpenOptions::new().append(true).open(&path)?;
+        write!(file, "{{\"year\":2025,\"da")?;
+
+        PerfHistory::append_to(&path, &entry(2, "aaa", false, [10, 20, 30]))?;
+        let history = PerfHistory::load(&path)?;
+        assert_eq!(
+            history.entries(),
+            [
+                entry(1, "aaa", false, [10, 20, 30]),
+                entry(2, "aaa", false, [10, 20, 30])
+            ]
+        );
+        assert_eq!(history.skipped(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut history = PerfHistory::load(dir.path().join("perf.jsonl"))?;
+        for entry in [
+            entry(1, "aaa", false, [100, 200, 300]),
+            entry(1, "aaa", false, [120, 200, 400]),
+            entry(1, "aaa", false, [110, 200, 350]),
+            // uncommitted changes on top of the base do not count for it
+            entry(1, "aaa", true, [1, 1, 1]),
+            entry(1, "bbb", false, [110, 150, 700]),
+            entry(2, "aaa", false, [50, 60, 0]),
+            entry(2, "bbb", true, [50, 66, 70]),
+            // never ran at the base
+            entry(3, "bbb", false, [50, 60, 70]),
+            // another account's input
+            PerfEntry {
+                profile: "alex".to_string(),
+                ..entry(1, "bbb", false, [1, 1, 1])
+            },
+        ] {
+            history.append(entry)?;
+        }
+
+        let changes = history.diff(2025, DEFAULT_PROFILE, "aaa");
+        let micros = |micros| Duration::from_micros(micros);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.day, change.step, change.before, change.after))
+                .collect::<Vec<_>>(),
+            [
+                (1, Step::Parse, micros(110), micros(110)),
+                (1, Step::Part(1), micros(200), micros(150)),
+                (1, Step::Part(2), micros(350), micros(700)),
+                (2, Step::Parse, micros(50), micros(50)),
+                (2, Step::Part(1), micros(60), micros(66)),
+            ]
+        );
+        assert_eq!(history.diff(2025, DEFAULT_PROFILE, "ccc"), []);
+        assert_eq!(history.diff(2024, DEFAULT_PROFILE, "aaa"), []);
+        assert_eq!(history.diff(2025, "alex", "aaa"), []);
+
+        assert_eq!(
+            render_changes(&changes, 5.0),
+            "Day  Step    Build      Before     After   Change\n\
+             \x20 1  parse   release   110.0µs   110.0µs    +0.0%\n\
+             \x20 1  part 1  release   200.0µs   150.0µs   -25.0%\n\
+             \x20 1  part 2  release   350.0µs   700.0µs  +100.0%  regressed\n\
+             \x20 2  parse   release    50.0µs    50.0µs    +0.0%\n\
+             \x20 2  part 1  release    60.0µs    66.0µs   +10.0%  regressed\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_perf() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new("http://localhost").with_data_dir(dir.path());
+        let micros = |micros: &[u64]| micros.iter().map(|&m| Duration::from_micros(m)).collect();
+        let timings = Timings {
+            input: None,
+            parse: micros(&[30, 10, 20]),
+            parts: [micros(&[5, 7, 6]), micros(&[1, 1, 1])],
+        };
+
+        let unimplemented = Some(Err(crate::PartError::Unimplemented));
+        assert!(!client.record_perf(2025, 5, &[None, unimplemented.clone()], &timings)?);
+        assert!(client.record_perf(
+            2025,
+            5,
+            &[Some(Ok("3".to_string())), unimplemented],
+            &timings
+        )?);
+
+        let history = PerfHistory::load(client.perf_path())?;
+        let [entry] = history.entries() else {
+            panic!("expected one entry, got {:?}", history.entries());
+        };
+        // the data directory is not a git repository
+        assert_eq!(entry.commit, None);
+        assert_eq!(entry.profile, DEFAULT_PROFILE);
+        assert_eq!(entry.runs, 3);
+        assert_eq!(entry.parse, Duration::from_micros(20));
+        assert_eq!(entry.parts, [Some(Duration::from_micros(6)), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_git() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(git_head(dir.path()), None);
+
+        let run = |args: &[&str]| {
+            let mut command = vec!["-c", "user.name=aoc", "-c", "user.email=aoc@localhost"];
+            command.extend(["-c", "commit.gpgsign=false"]);
+            command.extend(args);
+            git(dir.path(), &command)
+        };
+        run(&["init", "-q"])?;
+        std::fs::write(dir.path().join("day.rs"), "fn part1() {}")?;
+        run(&["add", "day.rs"])?;
+        run(&["commit", "-q", "-m", "Day 1"])?;
+
+        let (commit, dirty) = git_head(dir.path()).unwrap();
+        assert!(!dirty);
+        assert_eq!(git_resolve(dir.path(), "HEAD")?, commit);
+        assert_eq!(git_resolve(dir.path(), &commit[..7])?, commit);
+        assert!(git_resolve(dir.path(), "no-such-branch").is_err());
+
+        std::fs::write(dir.path().join("day.rs"), "fn part1() { todo!() }")?;
+        assert_eq!(git_head(dir.path()), Some((commit, true)));
+        Ok(())
+    }
+}
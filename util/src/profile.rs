@@ -0,0 +1,70 @@
+/// Name of the profile whose session is in AOC_SESSION.
+pub const DEFAULT_PROFILE: &str = "default";
+
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// A named AoC account to fetch inputs and submit answers with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub session: String,
+}
+
+/// Reads the session profiles from the environment, the default profile first.
+/// AOC_SESSION is the default profile and AOC_SESSION_<NAME> adds a profile called `<name>`.
+/// automatically reads them from .env file if present.
+pub fn profiles() -> Vec<Profile> {
+    let _ = dotenvy::dotenv();
+    profiles_from(std::env::vars())
+}
+
+/// Looks up a single profile by name, in any case, see [`profiles`].
+pub fn profile(name: &str) -> Option<Profile> {
+    let name = name.to_lowercase();
+    profiles().into_iter().find(|profile| profile.name == name)
+}
+
+fn profiles_from(vars: impl Iterator<Item = (String, String)>) -> Vec<Profile> {
+    let mut profiles: Vec<Profile> = vars
+        .filter_map(|(key, session)| {
+            let name = match key.strip_prefix(SESSION_VAR)? {
+                "" => DEFAULT_PROFILE.to_string(),
+                suffix => suffix.strip_prefix('_')?.to_lowercase(),
+            };
+            (!name.is_empty() && !session.is_empty()).then_some(Profile { name, session })
+        })
+        .collect();
+    profiles.sort_by_key(|profile| (profile.name != DEFAULT_PROFILE, profile.name.clone()));
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_from() {
+        let vars = [
+            ("AOC_SESSION_ZOE", "zoe-session"),
+            ("PATH", "/usr/bin"),
+            ("AOC_SESSION", "main-session"),
+            ("AOC_SESSION_ALEX", "alex-session"),
+            ("AOC_SESSIONS", "not-a-profile"),
+            ("AOC_SESSION_EMPTY", ""),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+
+        let names: Vec<(String, String)> = profiles_from(vars.into_iter())
+            .into_iter()
+            .map(|profile| (profile.name, profile.session))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("default".to_string(), "main-session".to_string()),
+                ("alex".to_string(), "alex-session".to_string()),
+                ("zoe".to_string(), "zoe-session".to_string()),
+            ]
+        );
+    }
+}
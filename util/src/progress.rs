@@ -0,0 +1,246 @@
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use scraper::{Html, Selector};
+
+use crate::{AocClient, FetchError, Input, InputSource, PartError, Solver};
+
+/// How far the solution of one part in a day crate has come.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Implementation {
+    /// There is no crate, or no function, for the part
+    Missing,
+    /// The part still panics with `todo!()` or `unimplemented!()`
+    Todo,
+    Done,
+}
+
+impl fmt::Display for Implementation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Implementation::Missing => write!(f, "missing"),
+            Implementation::Todo => write!(f, "todo!()"),
+            Implementation::Done => write!(f, "done"),
+        }
+    }
+}
+
+impl AocClient {
+    /// Fetches the calendar of a year and returns how many stars the account has for each
+    /// unlocked day.
+    pub async fn stars(&self, year: usize) -> Result<BTreeMap<usize, usize>, FetchError> {
+        println!("Fetching calendar for year {}", year);
+        let response = self.get(&format!("/{}", year)).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FetchError::Http(status));
+        }
+        Ok(parse_calendar(&response.text().await?))
+    }
+}
+
+/// Reads the stars of every unlocked day off a calendar page. Locked days are not links, so
+/// they are left out.
+pub fn parse_calendar(html: &str) -> BTreeMap<usize, usize> {
+    let document = Html::parse_document(html);
+    let days = Selector::parse("pre.calendar a[href]").unwrap();
+
+    document
+        .select(&days)
+        .filter_map(|day| {
+            let number = day.value().attr("href")?.rsplit('/').next()?.parse().ok()?;
+            let classes: Vec<&str> = day.value().classes().collect();
+            let stars = if classes.contains(&"calendar-verycomplete") {
+                2
+            } else if classes.contains(&"calendar-complete") {
+                1
+            } else {
+                0
+            };
+            Some((number, stars))
+        })
+        .collect()
+}
+
+/// How far both parts of a registered day have come, by solving `input` with them: a part
+/// still calling `todo!()` or `unimplemented!()`, even in a helper, is [`Implementation::Todo`].
+/// A part that panics otherwise says nothing either way, so it is judged by the source of the
+/// day crate in `crate_dir` like with [`local_progress`].
+pub fn solver_progress(
+    solver: &Solver,
+    input: &Input,
+    source: InputSource,
+    crate_dir: &Path,
+) -> [Implementation; 2] {
+    let mut local = None;
+    let mut progress = [Implementation::Done; 2];
+    for (i, part) in solver
+        .solve(input, None, source)
+        .parts
+        .into_iter()
+        .enumerate()
+    {
+        progress[i] = match part {
+            Some(Ok(_)) => Implementation::Done,
+            Some(Err(PartError::Unimplemented)) => Implementation::Todo,
+            _ => local.get_or_insert_with(|| local_progress(crate_dir))[i],
+        };
+    }
+    progress
+}
+
+/// How far both parts of the day crate in `crate_dir` have come, judging by its `src/lib.rs`,
+/// for days that are not registered.
+pub fn local_progress(crate_dir: &Path) -> [Implementation; 2] {
+    match std::fs::read_to_string(crate_dir.join("src").join("lib.rs")) {
+        Ok(source) => [part_status(&source, 1), part_status(&source, 2)],
+        Err(_) => [Implementation::Missing; 2],
+    }
+}
+
+/// Looks at the body of the first `fn part<n>` in a crate's source, the one of its
+/// [`Solution`](crate::Solution) impl or a free function, which ends at the first closing
+/// brace indented like the `fn`.
+pub fn part_status(source: &str, part: usize) -> Implementation {
+    let Some(start) = source.find(&format!("fn part{}(", part)) else {
+        return Implementation::Missing;
+    };
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source[line_start..start];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let body = &source[start..];
+    let body = &body[..body.find(&format!("\n{}}}", indent)).unwrap_or(body.len())];
+    if body.contains("todo!(") || body.contains("unimplemented!(") {
+        Implementation::Todo
+    } else {
+        Implementation::Done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_parse_calendar() {
+        assert_eq!(
+            parse_calendar(include_str!("../fixtures/calendar.html")),
+            BTreeMap::from([(1, 2), (2, 1), (3, 0)])
+        );
+    }
+
+    const SOURCE: &str = r#"pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1Params = ();
+    type Part2Params = ();
+
+    fn parse(input: &Input) -> Self::Parsed<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(lines: &Self::Parsed<'_>, _: ()) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Parsed<'_>, _: ()) -> Answer {
+        todo!("Implement part 2");
+    }
+}
+
+fn part1(lines: &[&str]) -> usize {
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_part1() {
+        todo!()
+    }
+}
+"#;
+
+    /// Days written before the `Solution` trait
+    const FREE_FUNCTIONS: &str = r#"pub fn part1(input: &str) -> String {
+    input.lines().count().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    todo!("Implement part 2");
+}
+"#;
+
+    #[rstest]
+    #[case(1, Implementation::Done)]
+    #[case(2, Implementation::Todo)]
+    #[case(3, Implementation::Missing)]
+    fn test_part_status(#[case] part: usize, #[case] expected: Implementation) {
+        assert_eq!(part_status(SOURCE, part), expected);
+        assert_eq!(part_status(FREE_FUNCTIONS, part), expected);
+    }
+
+    struct Unfinished;
+
+    impl crate::Solution for Unfinished {
+        type Parsed<'a> = Vec<usize>;
+        type Part1Params = ();
+        type Part2Params = ();
+
+        fn parse(input: &Input) -> Self::Parsed<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>, _: ()) -> crate::Answer {
+            parsed.iter().sum::<usize>().into()
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>, _: ()) -> crate::Answer {
+            helper(parsed)
+        }
+    }
+
+    // `todo!()` outside of `fn part2`, which the source is not searched for
+    fn helper(_: &[usize]) -> crate::Answer {
+        todo!()
+    }
+
+    #[test]
+    fn test_solver_progress() -> std::io::Result<()> {
+        let solver = Solver::new::<Unfinished>(1);
+        let dir = tempfile::tempdir()?;
+        assert_eq!(
+            solver_progress(
+                &solver,
+                &Input::new("1\n2\n"),
+                InputSource::Example,
+                dir.path()
+            ),
+            [Implementation::Done, Implementation::Todo]
+        );
+
+        // parsing panics, so the source has the last word
+        std::fs::create_dir_all(dir.path().join("src"))?;
+        std::fs::write(dir.path().join("src/lib.rs"), SOURCE)?;
+        assert_eq!(
+            solver_progress(&solver, &Input::new("x\n"), InputSource::Puzzle, dir.path()),
+            [Implementation::Done, Implementation::Todo]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_progress() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(local_progress(dir.path()), [Implementation::Missing; 2]);
+
+        std::fs::create_dir_all(dir.path().join("src"))?;
+        std::fs::write(dir.path().join("src/lib.rs"), SOURCE)?;
+        assert_eq!(
+            local_progress(dir.path()),
+            [Implementation::Done, Implementation::Todo]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,461 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use color_eyre::Result;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::{AocClient, Bounds, DEFAULT_BASE_URL, FetchError};
+
+const PART_TWO_HEADING: &str = "\n## Part Two\n";
+
+/// What AoC shows below a part once it is solved.
+const SOLVED_MARKER: &str = "Your puzzle answer was";
+
+/// How long a saved puzzle with only part 1 goes before the page is checked for part 2 again,
+/// in case part 1 was solved on the site.
+pub const PUZZLE_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// The example inputs and expected answers found on a puzzle page.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    /// Every distinct `<pre><code>` block, in the order they appear
+    pub blocks: Vec<String>,
+    /// The expected answer for each unlocked part, when one could be found
+    pub answers: Vec<Option<String>>,
+}
+
+impl AocClient {
+    /// Fetches the puzzle page for the given year and day.
+    /// Part 2 is only on the page once part 1 has been solved by this profile.
+    pub async fn get_puzzle_page(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        println!("Fetching puzzle for year {}, day {}", year, day);
+        let response = self.get(&format!("/{}/day/{}", year, day)).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FetchError::from_status(status, year, day));
+        }
+        Ok(response.text().await?)
+    }
+
+    /// Fetches the puzzle page and saves its text as Markdown to `day-XX/puzzle.md`.
+    /// Returns the number of parts that were unlocked.
+    pub async fn save_puzzle(&self, year: usize, day: usize) -> Result<usize, FetchError> {
+        let page = self.get_puzzle_page(year, day).await?;
+        self.write_puzzle(year, day, &page)
+    }
+
+    fn write_puzzle(&self, year: usize, day: usize, page: &str) -> Result<usize, FetchError> {
+        let (markdown, parts) = puzzle_markdown(page);
+        let path = puzzle_path(&self.day_dir(day));
+        std::fs::create_dir_all(self.day_dir(day))?;
+        std::fs::write(&path, markdown)?;
+        println!(
+            "Saved {} of year {}, day {} to {}",
+            if parts == 1 {
+                "part 1"
+            } else {
+                "parts 1 and 2"
+            },
+            year,
+            day,
+            path.display()
+        );
+        Ok(parts)
+    }
+
+    /// Saves the puzzle text when it has not been saved yet, or adds part 2 once the page
+    /// shows part 1 as solved. While only part 1 is saved, the page is checked again when
+    /// `submit_answer` recorded part 1 as correct, or else at most every [`PUZZLE_REFRESH`].
+    /// Does nothing without a session, e.g. in CI reading the inputs from the vault.
+    pub async fn refresh_puzzle(&self, year: usize, day: usize) -> Result<()> {
+        self.refresh_puzzle_at(year, day, SystemTime::now()).await
+    }
+
+    async fn refresh_puzzle_at(&self, year: usize, day: usize, now: SystemTime) -> Result<()> {
+        if self.session().is_err() {
+            return Ok(());
+        }
+        let path = puzzle_path(&self.day_dir(day));
+        let saved = match std::fs::read_to_string(&path) {
+            Ok(saved) => saved,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.save_puzzle(year, day).await?;
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if saved.contains(PART_TWO_HEADING) {
+            return Ok(());
+        }
+
+        let submitted = Bounds::load(self.bounds_path())?
+            .get(year, day, 1)
+            .is_some_and(|bounds| bounds.correct.is_some());
+        let checked = std::fs::metadata(&path)?.modified()?;
+        let stale = now.duration_since(checked).unwrap_or_default() >= PUZZLE_REFRESH;
+        if !submitted && !stale {
+            return Ok(());
+        }
+        let page = self.get_puzzle_page(year, day).await?;
+        if part1_solved(&page) {
+            self.write_puzzle(year, day, &page)?;
+        } else {
+            // remembers when the page was last checked
+            std::fs::File::options()
+                .append(true)
+                .open(&path)?
+                .set_modified(now)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where the Markdown puzzle text of a day crate is stored.
+pub fn puzzle_path(crate_dir: &Path) -> PathBuf {
+    crate_dir.join("puzzle.md")
+}
+
+/// Whether a puzzle page shows part 1 as solved, which is when it has part 2.
+pub fn part1_solved(html: &str) -> bool {
+    html.contains(SOLVED_MARKER)
+}
+
+/// Converts the puzzle articles of a page to Markdown, and counts how many parts there are.
+pub fn puzzle_markdown(html: &str) -> (String, usize) {
+    let document = Html::parse_document(html);
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    let articles: Vec<String> = document.select(&articles).map(article_markdown).collect();
+    (articles.join("\n\n") + "\n", articles.len())
+}
+
+fn article_markdown(article: ElementRef) -> String {
+    let mut blocks = Vec::new();
+    for child in article.children() {
+        let Some(element) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value()
+                && !text.trim().is_empty()
+            {
+                blocks.push(text.trim().to_string());
+            }
+            continue;
+        };
+        match element.value().name() {
+            "h2" => blocks.push(format!(
+                "## {}",
+                text(element).trim_matches(|c: char| c == '-' || c.is_whitespace())
+            )),
+            "pre" => {
+                let code = text(element);
+                let newline = if code.ends_with('\n') { "" } else { "\n" };
+                blocks.push(format!("```\n{}{}```", code, newline));
+            }
+            "ul" | "ol" => blocks.push(
+                element
+                    .child_elements()
+                    .map(|item| format!("- {}", inline_markdown(item).trim()))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            _ => blocks.push(inline_markdown(element).trim().to_string()),
+        }
+    }
+    blocks.join("\n\n")
+}
+
+fn inline_markdown(element: ElementRef) -> String {
+    let mut markdown = String::new();
+    for child in element.children() {
+        let Some(element) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                markdown.push_str(text);
+            }
+            continue;
+        };
+        match element.value().name() {
+            "code"
+                if element
+                    .select(&Selector::parse("em").unwrap())
+                    .next()
+                    .is_some() =>
+            {
+                markdown.push_str(&format!("**`{}`**", text(element)))
+            }
+            "code" => markdown.push_str(&format!("`{}`", text(element))),
+            "em" => markdown.push_str(&format!("**{}**", inline_markdown(element))),
+            "a" => {
+                let href = element.value().attr("href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{}{}", DEFAULT_BASE_URL, href)
+                } else {
+                    href.to_string()
+                };
+                markdown.push_str(&format!("[{}]({})", inline_markdown(element), href))
+            }
+            _ => markdown.push_str(&inline_markdown(element)),
+        }
+    }
+    markdown
+}
+
+/// Pulls the example blocks out of a puzzle page, and the expected answers.
+/// The answer to a part is taken to be the last emphasized code in its article,
+/// which is where AoC states the result for the example.
+pub fn extract_examples(html: &str) -> PuzzleExamples {
+    let document = Html::parse_document(html);
+    let articles = Selector::parse("article.day-desc").unwrap();
+    let blocks = Selector::parse("pre > code").unwrap();
+    let answers = Selector::parse("code > em, em > code").unwrap();
+
+    let mut examples = PuzzleExamples::default();
+    for article in document.select(&articles) {
+        for block in article.select(&blocks) {
+            let block = text(block);
+            if !examples.blocks.contains(&block) {
+                examples.blocks.push(block);
+            }
+        }
+        examples
+            .answers
+            .push(article.select(&answers).last().map(text));
+    }
+    examples
+}
+
+fn text(element: ElementRef) -> String {
+    element.text().collect()
+}
+
+/// Directory the example fixtures of a day crate live in.
+pub fn examples_dir(crate_dir: &Path) -> PathBuf {
+    crate_dir.join("examples")
+}
+
+/// Writes the examples as `examples/<n>.txt` and the answers as `examples/part<n>.answer`.
+pub fn write_examples(crate_dir: &Path, examples: &PuzzleExamples) -> std::io::Result<()> {
+    let dir = examples_dir(crate_dir);
+    std::fs::create_dir_all(&dir)?;
+    for (i, block) in examples.blocks.iter().enumerate() {
+        std::fs::write(dir.join(format!("{}.txt", i + 1)), block)?;
+    }
+    for (i, answer) in examples.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            std::fs::write(dir.join(format!("part{}.answer", i + 1)), answer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads example `n` of the day crate in `crate_dir`, see [`example!`](crate::example).
+pub fn read_example(crate_dir: &str, n: usize) -> String {
+    let path = examples_dir(Path::new(crate_dir)).join(format!("{}.txt", n));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "failed to read {}: {} (run `cargo run -p aoc -- examples <day>` to fetch it)",
+            path.display(),
+            e
+        )
+    })
+}
+
+/// Reads the expected example answer for a part, see [`example_answer!`](crate::example_answer).
+pub fn read_example_answer(crate_dir: &str, part: usize) -> String {
+    let path = examples_dir(Path::new(crate_dir)).join(format!("part{}.answer", part));
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+        .trim()
+        .to_string()
+}
+
+/// Reads example `n` from the `examples/` directory of the calling crate.
+#[macro_export]
+macro_rules! example {
+    ($n:expr) => {
+        $crate::read_example(env!("CARGO_MANIFEST_DIR"), $n)
+    };
+}
+
+/// Reads the expected example answer for a part from the `examples/` directory of the calling crate.
+#[macro_export]
+macro_rules! example_answer {
+    ($part:expr) => {
+        $crate::read_example_answer(env!("CARGO_MANIFEST_DIR"), $part)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_client;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 5: Cafeteria ---</h2>
+<p>For example:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<p>The ingredient IDs are then checked:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<p>In this example, <code><em>3</em></code> of the available <a href="/2025/day/5/input">ingredient IDs</a> are fresh.</p>
+</article>
+<p>Your puzzle answer was <code>862</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a &lt; b</code></pre>
+<p>In the above example, the ranges consider <em><code>14</code></em> ingredient IDs to be fresh.</p>
+</article>
+</main>
+</body>
+</html>
+"#;
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(PAGE),
+            PuzzleExamples {
+                blocks: vec!["3-5\n10-14\n\n1\n5\n".to_string(), "a < b".to_string()],
+                answers: vec![Some("3".to_string()), Some("14".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn test_puzzle_markdown() {
+        let (markdown, parts) = puzzle_markdown(PAGE);
+        assert_eq!(parts, 2);
+        assert!(markdown.contains(PART_TWO_HEADING));
+        assert_eq!(
+            markdown,
+            "## Day 5: Cafeteria
+
+For example:
+
+```
+3-5
+10-14
+
+1
+5
+```
+
+The ingredient IDs are then checked:
+
+```
+3-5
+10-14
+
+1
+5
+```
+
+In this example, **`3`** of the available [ingredient IDs](https://adventofcode.com/2025/day/5/input) are fresh.
+
+## Part Two
+
+```
+a < b
+```
+
+In the above example, the ranges consider **`14`** ingredient IDs to be fresh.
+"
+        );
+    }
+
+    /// [`PAGE`] before part 1 was solved
+    fn part1_page() -> String {
+        PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()].to_string()
+            + "</main>\n</body>\n</html>\n"
+    }
+
+    #[test]
+    fn test_part1_solved() {
+        assert!(part1_solved(PAGE));
+        assert!(!part1_solved(&part1_page()));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_puzzle() -> Result<()> {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+        let saved = || std::fs::read_to_string(puzzle_path(&client.day_dir(5)));
+
+        let part1 = Mock::given(method("GET"))
+            .and(path("/2025/day/5"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(part1_page()))
+            .expect(2)
+            .mount_as_scoped(&server)
+            .await;
+        client.refresh_puzzle_at(2025, 5, SystemTime::now()).await?;
+        assert!(!saved()?.contains(PART_TWO_HEADING));
+        let start = SystemTime::now();
+        // saved just now, so the page is not asked for again yet
+        client.refresh_puzzle_at(2025, 5, start).await?;
+        // part 1 was not solved when it was checked
+        let later = start + PUZZLE_REFRESH;
+        client.refresh_puzzle_at(2025, 5, later).await?;
+        assert!(!saved()?.contains(PART_TWO_HEADING));
+        client.refresh_puzzle_at(2025, 5, later).await?;
+        drop(part1);
+
+        // solved on the site since
+        Mock::given(method("GET"))
+            .and(path("/2025/day/5"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(PAGE))
+            .expect(1)
+            .mount(&server)
+            .await;
+        client
+            .refresh_puzzle_at(2025, 5, later + PUZZLE_REFRESH)
+            .await?;
+        assert!(saved()?.contains(PART_TWO_HEADING));
+        // with part 2 saved there is nothing left to check
+        client
+            .refresh_puzzle_at(2025, 5, later + PUZZLE_REFRESH * 2)
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_puzzle_without_session() -> Result<()> {
+        let server = wiremock::MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new(server.uri()).with_data_dir(dir.path());
+
+        client.refresh_puzzle(2025, 5).await?;
+        assert!(!puzzle_path(&client.day_dir(5)).exists());
+        assert!(server.received_requests().await.unwrap().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_and_read_examples() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_examples(dir.path(), &extract_examples(PAGE))?;
+
+        let crate_dir = dir.path().to_str().unwrap();
+        assert_eq!(read_example(crate_dir, 1), "3-5\n10-14\n\n1\n5\n");
+        assert_eq!(read_example(crate_dir, 2), "a < b");
+        assert_eq!(read_example_answer(crate_dir, 2), "14");
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+use std::{io::Read, path::PathBuf, time::Instant};
+
+use clap::Args;
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    AocClient, FetchError, Input, InputSource, PartError, PartResult, Solver, SystemClock, Timings,
+    examples_dir, profile, profiles,
+};
+
+/// Options for running a day against its puzzle input, or another input.
+#[derive(Debug, Clone, Default, Args)]
+pub struct RunArgs {
+    /// Only run this part
+    #[arg(long, value_parser = parse_part)]
+    pub part: Option<usize>,
+    /// Solve the input N times and show the min, median and max time of each step
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: u32,
+    /// Fetch the input with this session profile, set with AOC_SESSION_<NAME>
+    #[arg(long, conflicts_with = "all_profiles")]
+    pub profile: Option<String>,
+    /// Run against the input of every session profile
+    #[arg(long)]
+    pub all_profiles: bool,
+    /// Wait for the puzzle to unlock, then fetch the input and run right away
+    #[arg(long)]
+    pub wait: bool,
+    /// Run against this file instead of the puzzle input
+    #[arg(long, group = "local_input")]
+    pub input: Option<PathBuf>,
+    /// Run against standard input instead of the puzzle input
+    #[arg(long, group = "local_input")]
+    pub stdin: bool,
+    /// Run against example N from the `examples/` directory of the day
+    #[arg(long, value_name = "N", group = "local_input")]
+    pub example: Option<usize>,
+}
+
+fn parse_part(part: &str) -> Result<usize, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err("a day has parts 1 and 2".to_string()),
+    }
+}
+
+impl RunArgs {
+    /// Whether to run against something other than the puzzle input.
+    pub fn has_local_input(&self) -> bool {
+        self.input.is_some() || self.stdin || self.example.is_some()
+    }
+
+    /// The input to run against instead of the puzzle input, if one was given.
+    fn local_input(&self, client: &AocClient, day: usize) -> Result<Option<String>> {
+        let mut hint = "";
+        let path = match (&self.input, self.stdin, self.example) {
+            (Some(path), ..) => path.clone(),
+            (_, true, _) => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                return Ok(Some(input));
+            }
+            (.., Some(n)) => {
+                hint = ", fetch the examples with `cargo run -p aoc -- examples <day>`";
+                examples_dir(&client.day_dir(day)).join(format!("{}.txt", n))
+            }
+            _ => return Ok(None),
+        };
+        std::fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| eyre!("Could not read {}: {}{}", path.display(), e, hint))
+    }
+}
+
+/// The answer to a part as text, or why there is none.
+pub type PartAnswer = Result<String, PartError>;
+
+/// What came of running a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Ran {
+        /// What came of each part that ran, `None` for parts that did not
+        answers: [Option<PartAnswer>; 2],
+        timings: Timings,
+    },
+    /// The puzzle has not been unlocked yet
+    Locked,
+    Failed(String),
+}
+
+/// The outcome of running a day with one session profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: usize,
+    /// The session profile, when running with every profile
+    pub profile: Option<String>,
+    pub outcome: Outcome,
+}
+
+/// Runs a day against its puzzle input, or the input given in `args`, printing the answers
+/// as they come. Failures of the day, like a wrong answer in the vault, end up in the reports,
+/// so one failing day does not stop the others.
+pub async fn run_solver(args: &RunArgs, year: usize, solver: &Solver) -> Result<Vec<Report>> {
+    let client = AocClient::from_env();
+    let day = solver.day();
+    let report = |profile, outcome| Report {
+        day,
+        profile,
+        outcome,
+    };
+
+    let start = Instant::now();
+    if let Some(input) = args.local_input(&client, day)? {
+        let input_time = start.elapsed();
+        let (answers, mut timings) = solve(solver, &Input::new(input), args);
+        timings.input = Some(input_time);
+        // not the puzzle input, so the answers are not checked against earlier submissions
+        for (part, answer) in answers.iter().enumerate() {
+            match answer {
+                Some(Ok(answer)) => println!("Day {}: Part {}: {:?}", day, part + 1, answer),
+                Some(Err(e)) => print_part_error(day, part + 1, e),
+                None => {}
+            }
+        }
+        println!("Day {}: {}", day, timings.render());
+        return Ok(vec![report(None, Outcome::Ran { answers, timings })]);
+    }
+
+    if args.all_profiles {
+        let mut reports = Vec::new();
+        for profile in profiles() {
+            println!("Profile {}:", profile.name);
+            let client = client.clone().with_profile(&profile);
+            let outcome = run_with(&client, year, solver, args).await;
+            reports.push(report(Some(profile.name), outcome));
+        }
+        return Ok(reports);
+    }
+
+    let client = match &args.profile {
+        Some(name) => {
+            let profile = profile(name).ok_or_else(|| {
+                eyre!(
+                    "No session for profile {}: set AOC_SESSION_{}",
+                    name,
+                    name.to_uppercase()
+                )
+            })?;
+            client.with_profile(&profile)
+        }
+        None => client,
+    };
+    Ok(vec![report(
+        None,
+        run_with(&client, year, solver, args).await,
+    )])
+}
+
+async fn run_with(client: &AocClient, year: usize, solver: &Solver, args: &RunArgs) -> Outcome {
+    let day = solver.day();
+    match try_run_with(client, year, solver, args).await {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            println!("Day {}: {}", day, e);
+            Outcome::Failed(e.to_string())
+        }
+    }
+}
+
+async fn try_run_with(
+    client: &AocClient,
+    year: usize,
+    solver: &Solver,
+    args: &RunArgs,
+) -> Result<Outcome> {
+    let day = solver.day();
+    let start = Instant::now();
+    let input = if args.wait {
+        client.wait_for_input(year, day, &SystemClock).await
+    } else {
+        client.get_input(year, day).await
+    };
+    // waiting for the unlock is not worth timing
+    let input_time = (!args.wait).then(|| start.elapsed());
+    let input = match input {
+        Ok(input) => Input::new(input),
+        Err(e @ FetchError::NotYetUnlocked { .. }) => {
+            println!("Day {}: {}, come back later or run with --wait", day, e);
+            return Ok(Outcome::Locked);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if let Err(e) = client.refresh_puzzle(year, day).await {
+        println!("Day {}: could not save the puzzle text: {}", day, e);
+    }
+
+    let (answers, mut timings) = solve(solver, &input, args);
+    timings.input = input_time;
+    for (part, answer) in answers.iter().enumerate() {
+        match answer {
+            Some(Ok(answer)) => client.print_answer(year, day, part + 1, answer),
+            Some(Err(e)) => print_part_error(day, part + 1, e),
+            None => {}
+        }
+    }
+    println!("Day {}: {}", day, timings.render());
+
+    // the answers sealed with the input, so a fresh clone with the vault key catches regressions
+    // an entry the key cannot open was skipped when loading the input, and is here too
+    let expected = match client.vault_entry(year, day) {
+        Ok(entry) => entry.map(|entry| entry.answers).unwrap_or_default(),
+        Err(e) => {
+            println!(
+                "Day {}: could not read the vault, the answers are not checked: {}",
+                day, e
+            );
+            Default::default()
+        }
+    };
+    let mut regressed = Vec::new();
+    for (part, (result, expected)) in answers.iter().zip(expected).enumerate() {
+        if let (Some(Ok(result)), Some(expected)) = (result, expected)
+            && expected != *result
+        {
+            println!(
+                "Day {}: Part {}: the vault expects {:?}",
+                day,
+                part + 1,
+                expected
+            );
+            regressed.push(part + 1);
+        }
+    }
+    if !regressed.is_empty() {
+        return Err(eyre!(
+            "parts {:?} no longer give the answers in the vault",
+            regressed
+        ));
+    }
+    if let Err(e) = client.record_perf(year, day, &answers, &timings) {
+        println!("Day {}: could not record the times: {}", day, e);
+    }
+    Ok(Outcome::Ran { answers, timings })
+}
+
+/// Solves the input as often as `--repeat` asks, keeping the answers of the first run.
+fn solve(solver: &Solver, input: &Input, args: &RunArgs) -> ([Option<PartAnswer>; 2], Timings) {
+    let mut timings = Timings::default();
+    let source = match args.example {
+        Some(_) => InputSource::Example,
+        None => InputSource::Puzzle,
+    };
+    let solved = solver.solve(input, args.part, source);
+    timings.record(&solved);
+    for _ in 1..args.repeat {
+        timings.record(&solver.solve(input, args.part, source));
+    }
+    (answers(solved.parts), timings)
+}
+
+fn answers(results: [Option<PartResult>; 2]) -> [Option<PartAnswer>; 2] {
+    results.map(|result| result.map(|result| result.map(|answer| answer.to_string())))
+}
+
+fn print_part_error(day: usize, part: usize, e: &PartError) {
+    match e {
+        PartError::Panicked {
+            location: Some(location),
+            ..
+        } => println!("Day {}: Part {}: {} at {}", day, part, e, location),
+        _ => println!("Day {}: Part {}: {}", day, part, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_client;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        run: RunArgs,
+    }
+
+    #[test]
+    fn test_local_input() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new("http://localhost").with_data_dir(dir.path());
+        let examples = examples_dir(&client.day_dir(5));
+        std::fs::create_dir_all(&examples)?;
+        std::fs::write(examples.join("2.txt"), "3-5\n\n1\n")?;
+        std::fs::write(dir.path().join("custom.txt"), "10-14\n\n12\n")?;
+
+        let args =
+            |args: &[&str]| Cli::try_parse_from([&["aoc"], args].concat()).map(|cli| cli.run);
+        assert_eq!(args(&[])?.local_input(&client, 5)?, None);
+        assert_eq!(
+            args(&["--example", "2"])?.local_input(&client, 5)?,
+            Some("3-5\n\n1\n".to_string())
+        );
+        let custom = dir.path().join("custom.txt");
+        assert_eq!(
+            args(&["--input", custom.to_str().unwrap()])?.local_input(&client, 5)?,
+            Some("10-14\n\n12\n".to_string())
+        );
+        assert!(args(&["--example", "3"])?.local_input(&client, 5).is_err());
+        assert!(args(&["--example", "2", "--stdin"]).is_err());
+        assert!(args(&["--example", "2"])?.has_local_input());
+        assert_eq!(args(&["--part", "2"])?.part, Some(2));
+        assert!(args(&["--part", "3"]).is_err());
+        assert_eq!(args(&[])?.repeat, 1);
+        assert!(args(&["--repeat", "0"]).is_err());
+        Ok(())
+    }
+
+    struct Sum;
+
+    impl crate::Solution for Sum {
+        type Parsed<'a> = Vec<usize>;
+        type Part1Params = ();
+        type Part2Params = ();
+
+        fn parse(input: &Input) -> Self::Parsed<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>, _: ()) -> crate::Answer {
+            parsed.iter().sum::<usize>().into()
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>, _: ()) -> crate::Answer {
+            parsed.iter().product::<usize>().into()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_past_unreadable_vault() -> Result<()> {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/5/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("2\n3\n"))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path())
+            .with_vault_key("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let path = client
+            .vault()?
+            .unwrap()
+            .path(2025, 5, crate::DEFAULT_PROFILE);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, [0; 64])?;
+
+        let outcome = run_with(&client, 2025, &Solver::new::<Sum>(5), &RunArgs::default()).await;
+        let Outcome::Ran { answers, .. } = outcome else {
+            panic!("expected the day to run, got {:?}", outcome);
+        };
+        assert_eq!(
+            answers,
+            [Some(Ok("5".to_string())), Some(Ok("6".to_string()))]
+        );
+        Ok(())
+    }
+}
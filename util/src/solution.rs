@@ -0,0 +1,373 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{Input, PartError, catch::catch_part};
+
+/// The answer to a part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// For answers that overflow an `i64`
+    BigInt(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(answer) => write!(f, "{}", answer),
+            Answer::BigInt(answer) => write!(f, "{}", answer),
+            Answer::Text(answer) => write!(f, "{}", answer),
+        }
+    }
+}
+
+/// Picks the smallest variant an integer fits in.
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                // the conversions cannot fail for the smaller types
+                #[allow(irrefutable_let_patterns)]
+                fn from(answer: $int) -> Self {
+                    if let Ok(answer) = i64::try_from(answer) {
+                        Answer::Int(answer)
+                    } else if let Ok(answer) = i128::try_from(answer) {
+                        Answer::BigInt(answer)
+                    } else {
+                        Answer::Text(answer.to_string())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Text(answer.to_string())
+    }
+}
+
+/// A day's solution: parses the input once, then solves both parts from the parsed input.
+///
+/// Parts whose answer depends on a size that differs between the examples and the real
+/// input take it as a parameter, e.g. how many connections to make. The [`Default`] of the
+/// parameter is the value for the real input, and [`Solution::example_part1_params`] the one
+/// for the examples. Days without one use `()`.
+pub trait Solution {
+    /// The input after parsing, which may borrow from the input text.
+    type Parsed<'a>;
+    type Part1Params: Default;
+    type Part2Params: Default;
+
+    fn parse(input: &Input) -> Self::Parsed<'_>;
+    fn part1(parsed: &Self::Parsed<'_>, params: Self::Part1Params) -> Answer;
+    fn part2(parsed: &Self::Parsed<'_>, params: Self::Part2Params) -> Answer;
+
+    /// The parameters of part 1 for the examples, the same as for the real input unless overridden.
+    fn example_part1_params() -> Self::Part1Params {
+        Default::default()
+    }
+
+    /// The parameters of part 2 for the examples, the same as for the real input unless overridden.
+    fn example_part2_params() -> Self::Part2Params {
+        Default::default()
+    }
+
+    /// Solves part 1 of the input with the parameters for the real input.
+    fn solve_part1(input: &str) -> Answer {
+        let input = Input::new(input);
+        Self::part1(&Self::parse(&input), Default::default())
+    }
+
+    /// Solves part 2 of the input with the parameters for the real input.
+    fn solve_part2(input: &str) -> Answer {
+        let input = Input::new(input);
+        Self::part2(&Self::parse(&input), Default::default())
+    }
+}
+
+/// What a day is solved on, which picks the parameters of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, or any other input that is not an example
+    Puzzle,
+    /// One of the examples in the puzzle text
+    Example,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "input"),
+            InputSource::Example => write!(f, "example"),
+        }
+    }
+}
+
+/// A [`Solution`] with its types erased, so the solutions of every day fit in one registry.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    day: usize,
+    solve: fn(&Input, Option<usize>, InputSource) -> Solved,
+    parse: fn(&Input),
+    with_parsed: WithParsed,
+}
+
+type WithParsed = fn(&Input, InputSource, &mut dyn FnMut(&SolvePart<'_>));
+
+/// Solves a part of an input that was parsed already.
+pub type SolvePart<'a> = dyn Fn(usize) -> Answer + 'a;
+
+/// The answer to a part, or why there is none.
+pub type PartResult = Result<Answer, PartError>;
+
+/// What came of solving an input once, with how long each step took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    /// The answer to each part that was asked for
+    pub parts: [Option<PartResult>; 2],
+    pub parse_time: Duration,
+    /// How long each part took, `None` for parts that did not run
+    pub part_times: [Option<Duration>; 2],
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(day: usize) -> Self {
+        Self {
+            day,
+            solve: solve::<S>,
+            parse: parse::<S>,
+            with_parsed: with_parsed::<S>,
+        }
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    /// Parses the input once and solves `part` of it, or both parts without one, with the
+    /// parameters for where the input came from.
+    /// Parts that were not asked for are `None`. A panic only fails the part it happened in,
+    /// or both when parsing panics.
+    pub fn solve(&self, input: &Input, part: Option<usize>, source: InputSource) -> Solved {
+        (self.solve)(input, part, source)
+    }
+
+    /// Parses the input and throws the result away, to time the parser on its own.
+    pub fn parse(&self, input: &Input) {
+        (self.parse)(input)
+    }
+
+    /// Parses the input once, then hands `f` a function that solves a part of the parsed
+    /// input, to time the parts without the parser. Panics are not caught.
+    pub fn with_parsed(
+        &self,
+        input: &Input,
+        source: InputSource,
+        f: &mut dyn FnMut(&SolvePart<'_>),
+    ) {
+        (self.with_parsed)(input, source, f)
+    }
+}
+
+fn solve<S: Solution>(input: &Input, part: Option<usize>, source: InputSource) -> Solved {
+    let wanted = [part != Some(2), part != Some(1)];
+    let start = Instant::now();
+    let parsed = catch_part(|| S::parse(input));
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Solved {
+                parts: wanted.map(|wanted| wanted.then(|| Err(e.clone()))),
+                parse_time,
+                part_times: [None, None],
+            };
+        }
+    };
+
+    let mut parts = [None, None];
+    let mut part_times = [None, None];
+    for (i, wanted) in wanted.into_iter().enumerate() {
+        if !wanted {
+            continue;
+        }
+        let start = Instant::now();
+        parts[i] = Some(catch_part(|| solve_part::<S>(&parsed, i + 1, source)));
+        part_times[i] = Some(start.elapsed());
+    }
+    Solved {
+        parts,
+        parse_time,
+        part_times,
+    }
+}
+
+fn parse<S: Solution>(input: &Input) {
+    std::hint::black_box(S::parse(input));
+}
+
+fn with_parsed<S: Solution>(input: &Input, source: InputSource, f: &mut dyn FnMut(&SolvePart<'_>)) {
+    let parsed = S::parse(input);
+    f(&|part| solve_part::<S>(&parsed, part, source))
+}
+
+fn solve_part<S: Solution>(parsed: &S::Parsed<'_>, part: usize, source: InputSource) -> Answer {
+    match (part, source) {
+        (1, InputSource::Puzzle) => S::part1(parsed, Default::default()),
+        (1, InputSource::Example) => S::part1(parsed, S::example_part1_params()),
+        (_, InputSource::Puzzle) => S::part2(parsed, Default::default()),
+        (_, InputSource::Example) => S::part2(parsed, S::example_part2_params()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Answer::from(42usize), Answer::Int(42))]
+    #[case(Answer::from(-7i32), Answer::Int(-7))]
+    #[case(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128))]
+    #[case(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()))]
+    #[case(Answer::from("LGOE"), Answer::Text("LGOE".to_string()))]
+    fn test_answer_from(#[case] answer: Answer, #[case] expected: Answer) {
+        assert_eq!(answer, expected);
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::Int(-7).to_string(), "-7");
+        assert_eq!(
+            Answer::BigInt(i64::MAX as i128 + 1).to_string(),
+            "9223372036854775808"
+        );
+        assert_eq!(Answer::Text("a,b".to_string()).to_string(), "a,b");
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed<'a> = Vec<&'a str>;
+        /// Only lines at least this long count
+        type Part1Params = usize;
+        type Part2Params = ();
+
+        fn parse(input: &Input) -> Self::Parsed<'_> {
+            input.lines().collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>, min_len: usize) -> Answer {
+            parsed
+                .iter()
+                .filter(|line| line.len() >= min_len)
+                .count()
+                .into()
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>, _: ()) -> Answer {
+            parsed.concat().into()
+        }
+
+        fn example_part1_params() -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Input::new("a\r\nbb\r\n");
+        let parsed = Lines::parse(&input);
+        assert_eq!(Lines::part1(&parsed, 2), Answer::Int(1));
+        assert_eq!(Lines::solve_part1("a\nbb\n"), Answer::Int(2));
+        assert_eq!(Lines::solve_part2("a\r\nbb\r\n"), Answer::from("abb"));
+    }
+
+    #[test]
+    fn test_solver() {
+        const SOLVER: Solver = Solver::new::<Lines>(3);
+        let input = Input::new("a\nbb\n");
+        assert_eq!(SOLVER.day(), 3);
+        assert_eq!(
+            SOLVER.solve(&input, None, InputSource::Puzzle).parts,
+            [Some(Ok(Answer::Int(2))), Some(Ok(Answer::from("abb")))]
+        );
+        assert_eq!(
+            SOLVER.solve(&input, Some(1), InputSource::Example).parts,
+            [Some(Ok(Answer::Int(1))), None]
+        );
+        let solved = SOLVER.solve(&input, Some(2), InputSource::Puzzle);
+        assert_eq!(solved.parts, [None, Some(Ok(Answer::from("abb")))]);
+        assert!(solved.part_times[0].is_none() && solved.part_times[1].is_some());
+
+        let mut answers = Vec::new();
+        SOLVER.with_parsed(&input, InputSource::Puzzle, &mut |solve| {
+            answers.extend([solve(2), solve(1)])
+        });
+        SOLVER.with_parsed(&input, InputSource::Example, &mut |solve| {
+            answers.push(solve(1))
+        });
+        assert_eq!(
+            answers,
+            [Answer::from("abb"), Answer::Int(2), Answer::Int(1)]
+        );
+    }
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        type Parsed<'a> = Vec<usize>;
+        type Part1Params = ();
+        type Part2Params = ();
+
+        fn parse(input: &Input) -> Self::Parsed<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>, _: ()) -> Answer {
+            parsed.iter().sum::<usize>().into()
+        }
+
+        fn part2(_: &Self::Parsed<'_>, _: ()) -> Answer {
+            todo!("Implement part 2");
+        }
+    }
+
+    #[test]
+    fn test_solver_panics() {
+        let solver = Solver::new::<Unfinished>(10);
+        assert_eq!(
+            solver
+                .solve(&Input::new("1\n2\n"), None, InputSource::Puzzle)
+                .parts,
+            [
+                Some(Ok(Answer::Int(3))),
+                Some(Err(PartError::Unimplemented))
+            ]
+        );
+
+        let [Some(Err(part1)), Some(Err(part2))] = solver
+            .solve(&Input::new("1\nx\n"), None, InputSource::Puzzle)
+            .parts
+        else {
+            panic!("expected parsing to fail both parts");
+        };
+        assert_eq!(part1, part2);
+        assert!(matches!(part1, PartError::Panicked { .. }));
+    }
+}
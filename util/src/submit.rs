@@ -0,0 +1,404 @@
+use std::{
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{AocClient, Bounds};
+
+/// The verdict Advent of Code gave for a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmissionOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, AoC asks to wait before trying again.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part has already been solved, so AoC did not judge the answer.
+    AlreadySolved,
+}
+
+/// A single submission attempt as recorded in the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+/// Append-only record of every answer submitted to AoC, stored as JSON lines.
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match std::fs::File::open(&path) {
+            Ok(file) => BufReader::new(file)
+                .lines()
+                .map(|line| Ok(serde_json::from_str(&line?)?))
+                .collect::<Result<Vec<LedgerEntry>>>()?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { path, entries })
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    pub fn append(&mut self, entry: LedgerEntry) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Returns the verdict AoC already gave for this exact answer, if any.
+    /// Rate limited attempts were never judged, so they are ignored.
+    pub fn previous_verdict(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Option<&LedgerEntry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.year == year
+                && entry.day == day
+                && entry.part == part
+                && entry.answer == answer
+                && !matches!(entry.outcome, SubmissionOutcome::RateLimited { .. })
+        })
+    }
+
+    /// Returns how long is left of the most recent rate limit, if it has not expired yet.
+    pub fn remaining_wait(&self, now: u64) -> Option<Duration> {
+        self.entries
+            .iter()
+            .rev()
+            .find_map(|entry| match entry.outcome {
+                SubmissionOutcome::RateLimited { wait } => {
+                    let until = entry.submitted_at + wait.as_secs();
+                    (until > now).then(|| Duration::from_secs(until - now))
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Submits an answer for the given year, day and part to Advent of Code.
+/// See [`AocClient::submit_answer`].
+pub async fn submit_answer(
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<SubmissionOutcome> {
+    AocClient::from_env()
+        .submit_answer(year, day, part, answer)
+        .await
+}
+
+impl AocClient {
+    /// Submits an answer for the given year, day and part to Advent of Code.
+    /// Every attempt is recorded in the `.aoc_submissions.jsonl` ledger next to `.aoc_cache`.
+    /// Answers that AoC has already judged, or that fall outside the recorded too high / too low bounds,
+    /// are not resubmitted, the known verdict is returned instead.
+    pub async fn submit_answer(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<SubmissionOutcome> {
+        let mut ledger = Ledger::load(self.ledger_path())?;
+        let mut bounds = Bounds::load(self.bounds_path())?;
+        let now = unix_now();
+
+        if let Some(previous) = ledger.previous_verdict(year, day, part, answer) {
+            println!(
+                "Answer {:?} for year {}, day {}, part {} was already submitted, not resubmitting",
+                answer, year, day, part
+            );
+            return Ok(previous.outcome.clone());
+        }
+        let check = bounds.check(year, day, part, answer);
+        if let Some(outcome) = check.known_outcome() {
+            println!(
+                "Answer {:?} for year {}, day {}, part {} is {}, not submitting",
+                answer, year, day, part, check
+            );
+            return Ok(outcome);
+        }
+        if let Some(wait) = ledger.remaining_wait(now) {
+            return Ok(SubmissionOutcome::RateLimited { wait });
+        }
+
+        println!(
+            "Submitting answer {:?} for year {}, day {}, part {}",
+            answer, year, day, part
+        );
+        let response = self
+            .post_form(
+                &format!("/{}/day/{}/answer", year, day),
+                &[("level", part.to_string()), ("answer", answer.to_string())],
+            )
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(eyre!("Failed to submit answer: HTTP {}: {}", status, body));
+        }
+
+        let outcome = parse_submission_response(&body)?;
+        bounds.record(year, day, part, answer, &outcome);
+        bounds.save()?;
+        ledger.append(LedgerEntry {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome: outcome.clone(),
+            submitted_at: now,
+        })?;
+
+        if part == 1 && outcome == SubmissionOutcome::Correct {
+            // part 2 is now unlocked, pick up its text
+            if let Err(e) = self.save_puzzle(year, day).await {
+                println!("Could not refresh the puzzle text: {}", e);
+            }
+        }
+        Ok(outcome)
+    }
+}
+
+/// Parses the HTML page AoC returns after submitting an answer.
+pub fn parse_submission_response(body: &str) -> Result<SubmissionOutcome> {
+    if body.contains("That's the right answer") {
+        Ok(SubmissionOutcome::Correct)
+    } else if body.contains("You gave an answer too recently") {
+        Ok(SubmissionOutcome::RateLimited {
+            wait: parse_wait_time(body).unwrap_or(Duration::from_secs(60)),
+        })
+    } else if body.contains("Did you already complete it") {
+        Ok(SubmissionOutcome::AlreadySolved)
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Ok(SubmissionOutcome::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(SubmissionOutcome::TooLow)
+        } else {
+            Ok(SubmissionOutcome::Wrong)
+        }
+    } else {
+        Err(eyre!("Unrecognised answer response: {}", body))
+    }
+}
+
+/// Parses the "You have 1m 12s left to wait" part of a rate limited response.
+fn parse_wait_time(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    body[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_client;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "<article><p>That's the right answer!  You are one gold star closer to decorating the North Pole.</p></article>",
+        SubmissionOutcome::Correct
+    )]
+    #[case(
+        "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>",
+        SubmissionOutcome::Wrong
+    )]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>",
+        SubmissionOutcome::TooHigh
+    )]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>",
+        SubmissionOutcome::TooLow
+    )]
+    #[case(
+        "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p></article>",
+        SubmissionOutcome::RateLimited { wait: Duration::from_secs(34) }
+    )]
+    #[case(
+        "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.</p></article>",
+        SubmissionOutcome::RateLimited { wait: Duration::from_secs(242) }
+    )]
+    #[case(
+        "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>",
+        SubmissionOutcome::AlreadySolved
+    )]
+    fn test_parse_submission_response(#[case] body: &str, #[case] expected: SubmissionOutcome) {
+        assert_eq!(parse_submission_response(body).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_submission_response_unrecognised() {
+        assert!(parse_submission_response("<html>Please log in</html>").is_err());
+    }
+
+    #[test]
+    fn test_ledger() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("submissions.jsonl");
+
+        let mut ledger = Ledger::load(&path)?;
+        ledger.append(LedgerEntry {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: "42".to_string(),
+            outcome: SubmissionOutcome::TooLow,
+            submitted_at: 100,
+        })?;
+        ledger.append(LedgerEntry {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: "50".to_string(),
+            outcome: SubmissionOutcome::RateLimited {
+                wait: Duration::from_secs(30),
+            },
+            submitted_at: 110,
+        })?;
+
+        let ledger = Ledger::load(&path)?;
+        assert_eq!(ledger.entries().len(), 2);
+        assert_eq!(
+            ledger
+                .previous_verdict(2025, 1, 1, "42")
+                .map(|e| &e.outcome),
+            Some(&SubmissionOutcome::TooLow)
+        );
+        assert_eq!(ledger.previous_verdict(2025, 1, 1, "50"), None);
+        assert_eq!(ledger.previous_verdict(2025, 1, 2, "42"), None);
+        assert_eq!(ledger.remaining_wait(120), Some(Duration::from_secs(20)));
+        assert_eq!(ledger.remaining_wait(140), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_answer() -> Result<()> {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_string, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/2025/day/1/answer"))
+            .and(body_string("level=1&answer=42"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert_eq!(
+            client.submit_answer(2025, 1, 1, "42").await?,
+            SubmissionOutcome::TooLow
+        );
+        // known to be too low, so neither of these reach the server
+        assert_eq!(
+            client.submit_answer(2025, 1, 1, "42").await?,
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            client.submit_answer(2025, 1, 1, "7").await?,
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(Ledger::load(client.ledger_path())?.entries().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_answer_saves_part_two() -> Result<()> {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/2025/day/1/answer"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>One</p></article>\
+                 <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Two</p></article>",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+
+        assert_eq!(
+            client.submit_answer(2025, 1, 1, "42").await?,
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("day-01/puzzle.md"))?,
+            "## Day 1: Test\n\nOne\n\n## Part Two\n\nTwo\n"
+        );
+        // both parts are saved already
+        client.refresh_puzzle(2025, 1).await?;
+        Ok(())
+    }
+}
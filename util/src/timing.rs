@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use crate::Solved;
+
+/// How long each step of running a day took, over one or more runs of its solution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    /// Fetching the input, or loading it from the cache
+    pub input: Option<Duration>,
+    pub parse: Vec<Duration>,
+    /// Empty for parts that did not run
+    pub parts: [Vec<Duration>; 2],
+}
+
+/// The fastest, median and slowest of the times a step took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spread {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Spread {
+    /// `None` without any times.
+    pub fn new(times: &[Duration]) -> Option<Self> {
+        let mut times = times.to_vec();
+        times.sort();
+        Some(Spread {
+            min: *times.first()?,
+            median: times[times.len() / 2],
+            max: *times.last()?,
+        })
+    }
+}
+
+impl Timings {
+    /// Adds the times of one run of the solution.
+    pub fn record(&mut self, solved: &Solved) {
+        self.parse.push(solved.parse_time);
+        for (times, time) in self.parts.iter_mut().zip(solved.part_times) {
+            times.extend(time);
+        }
+    }
+
+    /// How many times the solution ran.
+    pub fn runs(&self) -> usize {
+        self.parse.len()
+    }
+
+    /// The median time of parsing and solving the parts that ran.
+    pub fn median_total(&self) -> Option<Duration> {
+        let parse = Spread::new(&self.parse)?.median;
+        Some(
+            self.parts
+                .iter()
+                .filter_map(|times| Spread::new(times))
+                .fold(parse, |total, part| total + part.median),
+        )
+    }
+
+    /// Renders the times on one line, e.g. `input 45.2µs, parse 120.3µs, part 1 12.0µs`.
+    /// Over several runs each step shows its min/median/max.
+    pub fn render(&self) -> String {
+        let step = |name: &str, times: &[Duration]| {
+            Spread::new(times).map(|spread| match times.len() {
+                1 => format!("{} {}", name, format_elapsed(spread.median)),
+                _ => format!(
+                    "{} {}/{}/{}",
+                    name,
+                    format_elapsed(spread.min),
+                    format_elapsed(spread.median),
+                    format_elapsed(spread.max)
+                ),
+            })
+        };
+        let steps: Vec<String> = [
+            step("parse", &self.parse),
+            step("part 1", &self.parts[0]),
+            step("part 2", &self.parts[1]),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let mut line = self
+            .input
+            .map(|input| format!("input {}, ", format_elapsed(input)))
+            .unwrap_or_default();
+        if self.runs() > 1 {
+            line.push_str(&format!("over {} runs (min/median/max): ", self.runs()));
+        }
+        line + &steps.join(", ")
+    }
+}
+
+/// Formats a duration with a unit that keeps a few significant digits, e.g. `12.3µs`.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let nanos = elapsed.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999ns")]
+    #[case(Duration::from_nanos(12_345), "12.3µs")]
+    #[case(Duration::from_micros(4_560), "4.6ms")]
+    #[case(Duration::from_millis(2_500), "2.50s")]
+    fn test_format_elapsed(#[case] elapsed: Duration, #[case] expected: &str) {
+        assert_eq!(format_elapsed(elapsed), expected);
+    }
+
+    #[test]
+    fn test_spread() {
+        let times = [3, 1, 2, 10].map(Duration::from_micros);
+        assert_eq!(
+            Spread::new(&times),
+            Some(Spread {
+                min: Duration::from_micros(1),
+                median: Duration::from_micros(3),
+                max: Duration::from_micros(10),
+            })
+        );
+        assert_eq!(Spread::new(&[]), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let micros = |times: &[u64]| times.iter().map(|&t| Duration::from_micros(t)).collect();
+        let once = Timings {
+            input: Some(Duration::from_micros(45)),
+            parse: micros(&[120]),
+            parts: [micros(&[12]), Vec::new()],
+        };
+        assert_eq!(once.render(), "input 45.0µs, parse 120.0µs, part 1 12.0µs");
+        assert_eq!(once.median_total(), Some(Duration::from_micros(132)));
+
+        let repeated = Timings {
+            input: None,
+            parse: micros(&[120, 100, 150]),
+            parts: [micros(&[12, 11, 13]), micros(&[30, 30, 31])],
+        };
+        assert_eq!(
+            repeated.render(),
+            "over 3 runs (min/median/max): parse 100.0µs/120.0µs/150.0µs, \
+             part 1 11.0µs/12.0µs/13.0µs, part 2 30.0µs/30.0µs/31.0µs"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    io::Write,
+    time::{Duration, SystemTime},
+};
+
+use chrono::{TimeZone, Utc};
+
+use crate::{AocClient, FetchError};
+
+/// How long to keep asking for the input after the unlock time, in case the site is a little late.
+const RETRY_WINDOW: Duration = Duration::from_secs(30);
+
+/// Source of the current time, so waiting for an unlock can be tested without waiting.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
+
+/// The real clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+/// How many puzzles a year has: 25 up to 2024, and 12 since the calendar was shortened in 2025.
+pub fn days_in(year: usize) -> usize {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Puzzles unlock at midnight EST (UTC-5) on the day of December they belong to.
+/// `None` for days without a puzzle.
+pub fn unlock_time(year: usize, day: usize) -> Option<SystemTime> {
+    if !(1..=days_in(year)).contains(&day) {
+        return None;
+    }
+    let year = i32::try_from(year).ok()?;
+    Utc.with_ymd_and_hms(year, 12, day as u32, 5, 0, 0)
+        .single()
+        .map(SystemTime::from)
+}
+
+impl AocClient {
+    /// Fetches the input for the given year and day, first waiting for the puzzle to unlock
+    /// while showing a countdown. Right after the unlock, the request is retried for a short while
+    /// until the input is available. The input is cached like with [`AocClient::get_input`].
+    pub async fn wait_for_input(
+        &self,
+        year: usize,
+        day: usize,
+        clock: &impl Clock,
+    ) -> Result<String, FetchError> {
+        let unlock = unlock_time(year, day).ok_or(FetchError::NoSuchDay { year, day })?;
+        let mut waited = false;
+        while let Ok(remaining) = unlock.duration_since(clock.now()) {
+            if remaining.is_zero() {
+                break;
+            }
+            let secs = remaining.as_secs_f64().ceil() as u64;
+            print!(
+                "\rDay {} unlocks in {:02}:{:02}:{:02}",
+                day,
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            let _ = std::io::stdout().flush();
+            clock.sleep(remaining.min(Duration::from_secs(1))).await;
+            waited = true;
+        }
+        if waited {
+            println!();
+        }
+
+        let deadline = unlock + RETRY_WINDOW;
+        loop {
+            match self.get_input(year, day).await {
+                Err(FetchError::NotYetUnlocked { .. }) if clock.now() < deadline => {
+                    clock.sleep(Duration::from_secs(1)).await
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::client::test_client;
+    use color_eyre::Result;
+    use rstest::rstest;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    /// A clock that only moves when something sleeps on it.
+    struct FakeClock(Mutex<SystemTime>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            *self.0.lock().unwrap()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            *self.0.lock().unwrap() += duration;
+        }
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(2025, 1),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_764_565_200))
+        );
+        assert_eq!(unlock_time(2025, 0), None);
+        assert_eq!(unlock_time(2025, 13), None);
+        assert_eq!(unlock_time(2025, 40), None);
+        assert!(unlock_time(2024, 25).is_some());
+    }
+
+    #[rstest]
+    #[case(2015, 25)]
+    #[case(2024, 25)]
+    #[case(2025, 12)]
+    #[case(2026, 12)]
+    fn test_days_in(#[case] year: usize, #[case] days: usize) {
+        assert_eq!(days_in(year), days);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_input() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/3/input"))
+            .respond_with(ResponseTemplate::new(404))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/3/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("987654321111111\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+        let unlock = unlock_time(2025, 3).unwrap();
+        let clock = FakeClock(Mutex::new(unlock - Duration::from_secs(90)));
+
+        assert_eq!(
+            client.wait_for_input(2025, 3, &clock).await?,
+            "987654321111111\n"
+        );
+        assert_eq!(clock.now(), unlock + Duration::from_secs(2));
+        // straight from the cache now
+        assert_eq!(client.get_input(2025, 3).await?, "987654321111111\n");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_input_gives_up() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/3/input"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path());
+        let unlock = unlock_time(2025, 3).unwrap();
+        let clock = FakeClock(Mutex::new(unlock));
+
+        assert!(matches!(
+            client.wait_for_input(2025, 3, &clock).await,
+            Err(FetchError::NotYetUnlocked { year: 2025, day: 3 })
+        ));
+        assert_eq!(clock.now(), unlock + RETRY_WINDOW);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_input_no_such_day() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let clock = FakeClock(Mutex::new(SystemTime::now()));
+
+        let error = test_client(&server, dir.path())
+            .wait_for_input(2025, 13, &clock)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            FetchError::NoSuchDay {
+                year: 2025,
+                day: 13
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "2025 has no day 13, its puzzles are on days 1 to 12 of December"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,354 @@
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::{
+    KeyInit, XChaCha20Poly1305, XNonce,
+    aead::{Aead, Payload},
+};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{AocClient, Bounds, DEFAULT_PROFILE, FetchError};
+
+/// Directory in the data directory the vault lives in, meant to be committed.
+pub const VAULT_DIR_NAME: &str = "vault";
+
+const NONCE_LEN: usize = 24;
+
+/// Bytes in a vault key. It has to be random rather than a passphrase, since anyone can try
+/// guesses offline against the committed files.
+pub const VAULT_KEY_LEN: usize = 32;
+
+/// What the vault keeps for a day: the input, and the answers AoC accepted for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultEntry {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// Inputs and answers encrypted with a key only their owner has, so they can be committed
+/// without publishing them.
+pub struct Vault {
+    /// The directory of this key's entries, so vaults sealed by different people with their
+    /// own keys live side by side
+    dir: PathBuf,
+    cipher: XChaCha20Poly1305,
+    /// Keeps the nonces, which are derived from the contents, from revealing equal contents
+    /// to anyone without the key
+    nonce_key: Vec<u8>,
+}
+
+impl Vault {
+    /// Opens the vault in `dir` with a key of [`VAULT_KEY_LEN`] random bytes in hex,
+    /// e.g. from `openssl rand -hex 32`. Anything else is rejected.
+    pub fn new(dir: impl AsRef<Path>, key: &str) -> Result<Self, FetchError> {
+        let key = parse_key(key)?;
+        let cipher_key = Sha256::new()
+            .chain_update("aoc vault key:")
+            .chain_update(key)
+            .finalize();
+        let nonce_key = Sha256::new()
+            .chain_update("aoc vault nonce:")
+            .chain_update(key)
+            .finalize();
+        let key_id = Sha256::new()
+            .chain_update("aoc vault id:")
+            .chain_update(key)
+            .finalize();
+        let key_id: String = key_id[..8].iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(Self {
+            dir: dir.as_ref().join(key_id),
+            cipher: XChaCha20Poly1305::new(&cipher_key),
+            nonce_key: nonce_key.to_vec(),
+        })
+    }
+
+    pub(crate) fn path(&self, year: usize, day: usize, profile: &str) -> PathBuf {
+        let name = if profile == DEFAULT_PROFILE {
+            format!("day-{:02}.vault", day)
+        } else {
+            format!("day-{:02}.{}.vault", day, profile)
+        };
+        self.dir.join(year.to_string()).join(name)
+    }
+
+    /// Decrypts the entry of a day, if the vault has one.
+    pub fn get(
+        &self,
+        year: usize,
+        day: usize,
+        profile: &str,
+    ) -> Result<Option<VaultEntry>, FetchError> {
+        let sealed = match std::fs::read(self.path(year, day, profile)) {
+            Ok(sealed) => sealed,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        if sealed.len() < NONCE_LEN {
+            return Err(FetchError::Vault("the file is truncated".to_string()));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(
+                XNonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &associated_data(year, day, profile),
+                },
+            )
+            .map_err(|_| FetchError::Vault("it was sealed with a different key".to_string()))?;
+        serde_json::from_slice(&plaintext)
+            .map(Some)
+            .map_err(|e| FetchError::Vault(e.to_string()))
+    }
+
+    /// Encrypts the entry of a day into the vault.
+    pub fn put(
+        &self,
+        year: usize,
+        day: usize,
+        profile: &str,
+        entry: &VaultEntry,
+    ) -> Result<(), FetchError> {
+        let plaintext = serde_json::to_vec(entry).map_err(|e| FetchError::Vault(e.to_string()))?;
+        let aad = associated_data(year, day, profile);
+        // derived from the contents, so sealing the same entry again leaves the file unchanged
+        let nonce = Sha256::new()
+            .chain_update(&self.nonce_key)
+            .chain_update(&aad)
+            .chain_update(&plaintext)
+            .finalize();
+        let nonce = XNonce::from_slice(&nonce[..NONCE_LEN]);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                nonce,
+                Payload {
+                    msg: &plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|e| FetchError::Vault(e.to_string()))?;
+
+        let path = self.path(year, day, profile);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, [nonce.as_slice(), &ciphertext].concat())?;
+        Ok(())
+    }
+}
+
+fn parse_key(key: &str) -> Result<[u8; VAULT_KEY_LEN], FetchError> {
+    let invalid = || {
+        FetchError::Vault(format!(
+            "the key must be {} random bytes in hex, e.g. from `openssl rand -hex {}`",
+            VAULT_KEY_LEN, VAULT_KEY_LEN
+        ))
+    };
+    let key = key.trim();
+    if key.len() != VAULT_KEY_LEN * 2 || !key.is_ascii() {
+        return Err(invalid());
+    }
+    let mut bytes = [0; VAULT_KEY_LEN];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&key[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/// Ties a sealed entry to its day, so a file renamed to another day does not decrypt.
+fn associated_data(year: usize, day: usize, profile: &str) -> Vec<u8> {
+    format!("{}:{}:{}", profile, year, day).into_bytes()
+}
+
+impl AocClient {
+    /// The vault in the data directory, when a vault key is set.
+    pub fn vault(&self) -> Result<Option<Vault>, FetchError> {
+        self.vault_key
+            .as_deref()
+            .map(|key| Vault::new(self.data_dir.join(VAULT_DIR_NAME), key))
+            .transpose()
+    }
+
+    /// The vault entry of a day for this profile, `None` without a vault key or entry.
+    pub fn vault_entry(&self, year: usize, day: usize) -> Result<Option<VaultEntry>, FetchError> {
+        match self.vault()? {
+            Some(vault) => vault.get(year, day, self.profile()),
+            None => Ok(None),
+        }
+    }
+
+    /// Seals the cached input of a day and its correct answers into the vault.
+    /// Returns `false` when the input is not cached.
+    pub fn seal(&self, year: usize, day: usize) -> Result<bool> {
+        let vault = self
+            .vault()?
+            .ok_or(FetchError::Vault("AOC_VAULT_KEY is not set".to_string()))?;
+        let Some(input) = self.cached_input(year, day)? else {
+            return Ok(false);
+        };
+        let bounds = Bounds::load(self.bounds_path())?;
+        let answer = |part| bounds.get(year, day, part).and_then(|b| b.correct.clone());
+        let entry = VaultEntry {
+            input,
+            answers: [answer(1), answer(2)],
+        };
+        vault.put(year, day, self.profile(), &entry)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_client;
+    use rstest::rstest;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const OTHER_KEY: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    fn entry() -> VaultEntry {
+        VaultEntry {
+            input: "3-5\n10-14\n\n1\n5\n".to_string(),
+            answers: [Some("3".to_string()), None],
+        }
+    }
+
+    #[test]
+    fn test_put_and_get() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let vault = Vault::new(dir.path(), KEY)?;
+
+        assert_eq!(vault.get(2025, 5, DEFAULT_PROFILE)?, None);
+        vault.put(2025, 5, DEFAULT_PROFILE, &entry())?;
+        assert_eq!(vault.get(2025, 5, DEFAULT_PROFILE)?, Some(entry()));
+        assert_eq!(vault.get(2025, 5, "alex")?, None);
+
+        let path = vault.path(2025, 5, DEFAULT_PROFILE);
+        assert!(path.starts_with(dir.path()) && path.ends_with("2025/day-05.vault"));
+        let sealed = std::fs::read(&path)?;
+        assert!(!String::from_utf8_lossy(&sealed).contains("10-14"));
+        // sealing again gives the same file, so an unchanged vault has no diff
+        vault.put(2025, 5, DEFAULT_PROFILE, &entry())?;
+        assert_eq!(std::fs::read(&path)?, sealed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_key() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let vault = Vault::new(dir.path(), KEY)?;
+        let other = Vault::new(dir.path(), OTHER_KEY)?;
+        vault.put(2025, 5, DEFAULT_PROFILE, &entry())?;
+
+        // everyone's entries live side by side, so sealing does not overwrite another key's
+        assert_eq!(other.get(2025, 5, DEFAULT_PROFILE)?, None);
+        let theirs = VaultEntry {
+            input: "1-2
+
+1
+"
+            .to_string(),
+            answers: [None, None],
+        };
+        other.put(2025, 5, DEFAULT_PROFILE, &theirs)?;
+        assert_eq!(vault.get(2025, 5, DEFAULT_PROFILE)?, Some(entry()));
+        assert_eq!(other.get(2025, 5, DEFAULT_PROFILE)?, Some(theirs));
+
+        std::fs::copy(
+            vault.path(2025, 5, DEFAULT_PROFILE),
+            other.path(2025, 5, DEFAULT_PROFILE),
+        )?;
+        assert!(matches!(
+            other.get(2025, 5, DEFAULT_PROFILE),
+            Err(FetchError::Vault(_))
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("secret")]
+    #[case("correct horse battery staple with a few more words")]
+    #[case(&KEY[..62])]
+    #[case(&KEY.replace('0', "g"))]
+    fn test_invalid_key(#[case] key: &str) {
+        assert!(matches!(
+            Vault::new("vault", key),
+            Err(FetchError::Vault(_))
+        ));
+    }
+
+    #[test]
+    fn test_moved_entry() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let vault = Vault::new(dir.path(), KEY)?;
+        vault.put(2025, 5, DEFAULT_PROFILE, &entry())?;
+        std::fs::rename(
+            vault.path(2025, 5, DEFAULT_PROFILE),
+            vault.path(2025, 6, DEFAULT_PROFILE),
+        )?;
+
+        assert!(matches!(
+            vault.get(2025, 6, DEFAULT_PROFILE),
+            Err(FetchError::Vault(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_from_vault() -> Result<()> {
+        use wiremock::MockServer;
+
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let client = AocClient::new(server.uri())
+            .with_data_dir(dir.path())
+            .with_vault_key(KEY);
+        client
+            .vault()?
+            .unwrap()
+            .put(2025, 5, DEFAULT_PROFILE, &entry())?;
+
+        // no session needed, the input comes out of the vault and into the cache
+        assert_eq!(client.get_input(2025, 5).await?, entry().input);
+        assert_eq!(client.cached_input(2025, 5)?, Some(entry().input));
+        assert!(server.received_requests().await.unwrap().is_empty());
+
+        // and goes back into the vault, with the answers AoC accepted
+        std::fs::remove_dir_all(dir.path().join(VAULT_DIR_NAME))?;
+        let mut bounds = Bounds::load(client.bounds_path())?;
+        bounds.record(2025, 5, 1, "3", &crate::SubmissionOutcome::Correct);
+        bounds.save()?;
+        assert!(client.seal(2025, 5)?);
+        assert_eq!(client.vault_entry(2025, 5)?, Some(entry()));
+        assert!(!client.seal(2025, 6)?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_input_past_unreadable_vault() -> Result<()> {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/5/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(entry().input))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir()?;
+        let client = test_client(&server, dir.path()).with_vault_key(KEY);
+        let path = client.vault()?.unwrap().path(2025, 5, DEFAULT_PROFILE);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, [0; 64])?;
+
+        // the vault only saves a fetch, so an entry it cannot open is fetched instead
+        assert_eq!(client.get_input(2025, 5).await?, entry().input);
+        Ok(())
+    }
+}
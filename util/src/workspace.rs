@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::{Result, eyre::eyre};
+
+/// Finds the root of the cargo workspace, so local state ends up in one place no matter
+/// which directory a binary or test runs from.
+/// Looks upwards from the directory `util` was built in first, then from the current directory,
+/// and falls back to the current directory.
+pub fn workspace_root() -> PathBuf {
+    find_workspace_root(Path::new(env!("CARGO_MANIFEST_DIR")))
+        .or_else(|| find_workspace_root(&std::env::current_dir().ok()?))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// The puzzle year of the workspace, from `year` in the `[workspace.metadata.aoc]` table of
+/// the workspace manifest, or else from the digits the workspace directory name ends in,
+/// e.g. `adventofcode2025`.
+pub fn aoc_year() -> Result<usize> {
+    let root = workspace_root();
+    std::fs::read_to_string(root.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| year_from_manifest(&manifest))
+        .or_else(|| year_from_path(&root.canonicalize().unwrap_or(root.clone())))
+        .ok_or_else(|| {
+            eyre!(
+                "Could not tell the puzzle year: add `[workspace.metadata.aoc]` with `year = 2025` to {}",
+                root.join("Cargo.toml").display()
+            )
+        })
+}
+
+fn year_from_manifest(manifest: &str) -> Option<usize> {
+    let manifest: toml::Table = manifest.parse().ok()?;
+    let year = manifest
+        .get("workspace")?
+        .get("metadata")?
+        .get("aoc")?
+        .get("year")?
+        .as_integer()?;
+    usize::try_from(year).ok()
+}
+
+fn year_from_path(root: &Path) -> Option<usize> {
+    let name = root.file_name()?.to_str()?;
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 4 {
+        name[name.len() - 4..].parse().ok()
+    } else {
+        None
+    }
+}
+
+fn find_workspace_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest| {
+                manifest
+                    .parse::<toml::Table>()
+                    .is_ok_and(|manifest| manifest.contains_key("workspace"))
+            })
+        })
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_workspace_root() {
+        let root = workspace_root();
+        assert!(root.join("util").join("Cargo.toml").exists());
+    }
+
+    #[test]
+    fn test_aoc_year() -> Result<()> {
+        assert_eq!(aoc_year()?, 2025);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("[workspace]\n\n[workspace.metadata.aoc]\nyear = 2024\n", Some(2024))]
+    #[case(
+        "[workspace.metadata.aoc]\n# the year\nyear=2026\n[workspace.dependencies]\n",
+        Some(2026)
+    )]
+    #[case("[workspace]\n[workspace.dependencies]\nyear = 2024\n", None)]
+    #[case("[workspace.metadata.aoc]\nyear = \"soon\"\n", None)]
+    #[case("[workspace.metadata.aoc]\nyear = 2023 # of the puzzles\n", Some(2023))]
+    #[case("[workspace.metadata]\naoc = { year = 2022 }\n", Some(2022))]
+    #[case(
+        "[workspace]\nmetadata.aoc.year = 2021\nmembers = [\"util\"]\n",
+        Some(2021)
+    )]
+    #[case(
+        "[workspace.metadata.aoc]\n[workspace.metadata.other]\n[workspace.metadata.aoc.more]\n",
+        None
+    )]
+    #[case("[workspace.metadata.aoc]\nyear = -1\n", None)]
+    #[case("[workspace.metadata.aoc\nyear = 2024\n", None)]
+    fn test_year_from_manifest(#[case] manifest: &str, #[case] expected: Option<usize>) {
+        assert_eq!(year_from_manifest(manifest), expected);
+    }
+
+    #[rstest]
+    #[case("/home/me/adventofcode2024", Some(2024))]
+    #[case("/home/me/aoc-2023", Some(2023))]
+    #[case("/home/me/aoc", None)]
+    #[case("/home/me/aoc123456", None)]
+    fn test_year_from_path(#[case] path: &str, #[case] expected: Option<usize>) {
+        assert_eq!(year_from_path(Path::new(path)), expected);
+    }
+
+    #[test]
+    fn test_find_workspace_root() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace] # of the puzzles\nmembers = [\"day-01\"]\n",
+        )?;
+        std::fs::create_dir_all(dir.path().join("day-01/src"))?;
+        std::fs::write(
+            dir.path().join("day-01/Cargo.toml"),
+            "[package]\nname = \"day-01\"\n",
+        )?;
+
+        assert_eq!(
+            find_workspace_root(&dir.path().join("day-01/src")),
+            Some(dir.path().to_path_buf())
+        );
+        Ok(())
+    }
+}